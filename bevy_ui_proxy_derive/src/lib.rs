@@ -0,0 +1,107 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Ident};
+
+/// Implements `ProxyUiApply` by copying values from `ProxyUiNodeState` into fields marked with
+/// `#[proxy(...)]`.
+///
+/// Also generates an associated `register` function that adds the apply system, call it with the
+/// app, `MyComponent::register(&mut app)`, which is the same as
+/// `app.register_proxy_applicator::<MyComponent>()`.
+///
+/// supported values:
+/// - `world_width`, `world_height` - `f32`
+/// - `world_size` - `Vec2`
+/// - `world_rect` - `Rect`
+/// - `world_transform` - `Transform`
+/// - `ui_width`, `ui_height` - `f32`
+/// - `ui_size` - `Vec2`
+/// - `ui_rect`, `logical_rect`, `physical_rect` - `Rect`
+/// - `visible` - `bool`
+#[proc_macro_derive(ProxyUiApply, attributes(proxy))]
+pub fn derive_proxy_ui_apply(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            input,
+            "ProxyUiApply can only be derived for structs",
+        ));
+    };
+
+    let mut assignments = Vec::new();
+    for (index, field) in data.fields.iter().enumerate() {
+        let member = match &field.ident {
+            Some(ident) => quote!(#ident),
+            None => {
+                let index = syn::Index::from(index);
+                quote!(#index)
+            }
+        };
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("proxy"))
+        {
+            let value: Ident = attr.parse_args()?;
+            let expr = state_value(&value)?;
+            assignments.push(quote!(self.#member = #expr;));
+        }
+    }
+
+    if assignments.is_empty() && !matches!(data.fields, Fields::Unit) {
+        return Err(syn::Error::new_spanned(
+            input,
+            "ProxyUiApply expects at least one field marked with #[proxy(...)]",
+        ));
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::bevy_ui_proxy::prelude::ProxyUiApply for #name #ty_generics #where_clause {
+            fn apply(&mut self, state: &::bevy_ui_proxy::prelude::ProxyUiNodeState) {
+                #(#assignments)*
+            }
+        }
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// adds the system applying the proxy state to this component,
+            /// runs in `ProxyUiSet::Apply`
+            pub fn register<A: ::bevy_ui_proxy::prelude::ProxyUiAppExt>(app: &mut A) -> &mut A {
+                app.register_proxy_applicator::<Self>()
+            }
+        }
+    })
+}
+
+fn state_value(value: &Ident) -> syn::Result<TokenStream2> {
+    let expr = match value.to_string().as_str() {
+        "world_width" => quote!(state.get_world_rect().width()),
+        "world_height" => quote!(state.get_world_rect().height()),
+        "world_size" => quote!(state.get_world_rect().size()),
+        "world_rect" => quote!(*state.get_world_rect()),
+        "world_transform" => quote!(state.get_world_transform()),
+        "ui_width" => quote!(state.get_ui_rect().width()),
+        "ui_height" => quote!(state.get_ui_rect().height()),
+        "ui_size" => quote!(state.get_ui_rect().size()),
+        "ui_rect" => quote!(*state.get_ui_rect()),
+        "logical_rect" => quote!(*state.get_logical_rect()),
+        "physical_rect" => quote!(*state.get_physical_rect()),
+        "visible" => quote!(state.get_view_visibility().get()),
+        _ => {
+            return Err(syn::Error::new_spanned(
+                value,
+                format!("unknown proxy value `{value}`"),
+            ))
+        }
+    };
+    Ok(expr)
+}
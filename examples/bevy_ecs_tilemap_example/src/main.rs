@@ -8,12 +8,11 @@ fn main() {
     App::new()
         .add_plugins((
             DefaultPlugins,
-            BevyUiProxyPlugin,
+            BevyUiProxyPlugin::default(),
             WorldInspectorPlugin::default(),
         ))
         .add_plugins(TilemapPlugin)
         .add_systems(Startup, setup)
-        .add_systems(Update, update)
         .run()
 }
 
@@ -67,6 +66,9 @@ fn create_tilemap(commands: &mut Commands, asset_server: Res<AssetServer>) -> En
 fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
     commands.spawn(Camera2dBundle::default());
     let proxy_target_entity = create_tilemap(&mut commands, asset_server);
+    commands
+        .entity(proxy_target_entity)
+        .insert(ProxyUiTilemap::new(ProxyUiFit::Contain));
 
     commands
         .spawn(NodeBundle {
@@ -118,64 +120,3 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
                 });
         });
 }
-
-#[allow(clippy::type_complexity)]
-fn update(
-    mut commands: Commands,
-    query: Query<
-        (
-            Entity,
-            &ProxyUiNodeState,
-            &TilemapSize,
-            &TilemapGridSize,
-            &TilemapType,
-        ),
-        (With<ProxyUiProxied>, Changed<ProxyUiNodeState>),
-    >,
-) {
-    for (entity, node_state, tilemap_size, tilemap_grid_size, tilemap_type) in query.iter() {
-        let ui_scale = *node_state.get_ui_scale();
-
-        let world_rect = node_state.get_world_rect();
-        let rect = Rect {
-            min: world_rect.min * ui_scale,
-            max: world_rect.max * ui_scale,
-        };
-
-        // blindly casting u32 to f32 because it's unlikely for a tilemap's tile size or the result to be over 2^24
-        let tilemap_width = tilemap_grid_size.x * tilemap_size.x as f32;
-        let tilemap_height = tilemap_grid_size.y * tilemap_size.y as f32;
-
-        // Calculate center position of the UI rect
-        let rect_center = rect.center();
-        let rect_center_x = rect_center.x;
-        let rect_center_y = rect_center.y;
-
-        // create the transform that gives us the center point for the tilemap
-        let tilemap_transform =
-            get_tilemap_center_transform(tilemap_size, tilemap_grid_size, tilemap_type, 0.0);
-
-        // create the transform that will scale the tilemap to match the ui rect
-        let scale_x = rect.width() / tilemap_width;
-        let scale_y = rect.height() / tilemap_height;
-        let tilemap_scale = Vec3::new(scale_x, scale_y, 1.0);
-        let transform_scale = Transform {
-            scale: tilemap_scale,
-            ..default()
-        };
-
-        // flip y coordinates to match world
-        let transform = Transform::from_xyz(rect_center_x, -rect_center_y, 0.0);
-
-        let corrected_global_transform = GlobalTransform::from(transform.compute_affine())
-            // scale tilemap to fit full rect
-            .mul_transform(transform_scale)
-            // apply tilemap transform to get center point
-            .mul_transform(tilemap_transform);
-
-        commands
-            .entity(entity)
-            .insert(corrected_global_transform)
-            .insert(node_state.get_computed_visibility());
-    }
-}
@@ -8,7 +8,7 @@ fn main() {
     App::new()
         .add_plugins((
             DefaultPlugins,
-            BevyUiProxyPlugin,
+            BevyUiProxyPlugin::default(),
             WorldInspectorPlugin::default(),
         ))
         .add_systems(Startup, setup)
@@ -93,29 +93,22 @@ fn update(
     query: Query<(Entity, &ProxyUiNodeState), (With<ProxyUiProxied>, Changed<ProxyUiNodeState>)>,
 ) {
     for (entity, node_state) in query.iter() {
-        let scale = *node_state.get_ui_scale();
-        let rect = node_state.get_ui_rect();
-        let rect = Rect {
-            min: rect.min * scale,
-            max: rect.max * scale,
-        };
+        let rect = node_state.get_world_rect();
         let radius = {
             let mut radius_val = rect.height().min(rect.width());
             if radius_val == 0.0 {
                 radius_val = rect.height().max(rect.width());
             }
             // radius has to be non zero
-            (radius_val * scale / 2.0).max(0.001)
+            (radius_val / 2.0).max(0.001)
         };
         commands
             .entity(entity)
             .insert(Mesh2dHandle(meshes.add(Circle { radius })))
             // if we want to maintain the relative transform to our parent
-            //.insert(*node_state.get_transform())
+            //.insert(node_state.get_world_transform())
             // if we want to ignore the relative transform to our parent
-            .insert(GlobalTransform::from(
-                node_state.get_transform().compute_affine(),
-            ))
+            .insert(GlobalTransform::from(node_state.get_world_transform()))
             .insert(node_state.get_computed_visibility());
     }
 }
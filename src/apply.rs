@@ -0,0 +1,73 @@
+use bevy::prelude::*;
+
+use crate::prelude::*;
+use crate::system::proxy_ui_apply::proxy_ui_apply;
+
+/// Lays out a component on a proxied target from its `ProxyUiNodeState`,
+/// registered with `App::register_proxy_applicator`
+pub trait ProxyUiApply: Component {
+    fn apply(&mut self, state: &ProxyUiNodeState);
+}
+
+pub trait ProxyUiAppExt {
+    /// calls `ProxyUiApply::apply` for every proxied entity with `T` whenever its state changes,
+    /// runs in `ProxyUiSet::Apply`
+    fn register_proxy_applicator<T: ProxyUiApply>(&mut self) -> &mut Self;
+}
+
+impl ProxyUiAppExt for App {
+    fn register_proxy_applicator<T: ProxyUiApply>(&mut self) -> &mut Self {
+        self.add_systems(Update, proxy_ui_apply::<T>.in_set(ProxyUiSet::Apply))
+    }
+}
+
+#[cfg(all(test, feature = "derive"))]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use crate::system::proxy_ui_added::proxy_ui_added;
+    use crate::system::proxy_ui_update::proxy_ui_update;
+    use crate::test::lib::*;
+
+    use super::*;
+
+    #[derive(Debug, Default, Component, ProxyUiApply)]
+    struct TriggerVolume {
+        #[proxy(world_width)]
+        width: f32,
+        #[proxy(world_height)]
+        height: f32,
+        #[proxy(ui_rect)]
+        ui_rect: Rect,
+        #[proxy(visible)]
+        visible: bool,
+    }
+
+    #[test]
+    fn test_derive_proxy_ui_apply() {
+        let mut app = setup_test_app();
+        TriggerVolume::register(&mut app);
+        let proxied_target_entity = app.world.spawn(TriggerVolume::default()).id();
+        app.world.spawn(ProxyUi::proxy(proxied_target_entity));
+        app.world.run_system_once(proxy_ui_added);
+        app.world.run_system_once(proxy_ui_update);
+        app.update();
+
+        let node_state = app
+            .world
+            .get::<ProxyUiNodeState>(proxied_target_entity)
+            .unwrap()
+            .clone();
+        let trigger_volume = app
+            .world
+            .get::<TriggerVolume>(proxied_target_entity)
+            .unwrap();
+        assert_eq!(trigger_volume.width, node_state.get_world_rect().width());
+        assert_eq!(trigger_volume.height, node_state.get_world_rect().height());
+        assert_eq!(trigger_volume.ui_rect, *node_state.get_ui_rect());
+        assert_eq!(
+            trigger_volume.visible,
+            node_state.get_view_visibility().get()
+        );
+    }
+}
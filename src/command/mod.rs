@@ -0,0 +1,3 @@
+pub use unlink_proxy_ui::*;
+
+pub mod unlink_proxy_ui;
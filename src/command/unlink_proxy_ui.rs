@@ -0,0 +1,255 @@
+use bevy::ecs::system::Command;
+use bevy::prelude::*;
+
+use crate::prelude::*;
+use crate::resource::proxy_ui_entity_map::ProxyUiEntityMap;
+
+/// Removes the link between a proxy ui node and its proxied target.
+///
+/// `entity` can be either side of the link
+#[derive(Debug, Clone, Copy)]
+pub struct UnlinkProxyUi {
+    pub entity: Entity,
+}
+
+impl Command for UnlinkProxyUi {
+    fn apply(self, world: &mut World) {
+        let entity = self.entity;
+        let link = {
+            let mut proxy_ui_entity_map = world.resource_mut::<ProxyUiEntityMap>();
+            if proxy_ui_entity_map.contains_proxy(&entity) {
+                proxy_ui_entity_map.remove_by_proxy_entity(&entity)
+            } else if proxy_ui_entity_map.is_proxied(&entity) {
+                proxy_ui_entity_map.remove_by_proxied_target_entity(&entity)
+            } else {
+                None
+            }
+        };
+
+        // fall back to the components in case the map is out of sync
+        let (proxied_target_entity, proxy_ui_entity) = match link {
+            Some(link) => link,
+            None => {
+                let Some(entity_ref) = world.get_entity(entity) else {
+                    debug!("failed to unlink {entity:?}, entity does not exist");
+                    return;
+                };
+                if let Some(proxy_ui_target) = entity_ref.get::<ProxyUiTarget>() {
+                    (proxy_ui_target.target_entity(), entity)
+                } else if let Some(proxy_ui_proxied) = entity_ref.get::<ProxyUiProxied>() {
+                    (entity, proxy_ui_proxied.get_proxy_ui_entity())
+                } else {
+                    debug!("failed to unlink {entity:?}, entity is not linked");
+                    return;
+                }
+            }
+        };
+
+        debug!("unlinking proxy ui {proxy_ui_entity:?} -> {proxied_target_entity:?}");
+        let mut was_camera_local = false;
+        let mut render_target = None;
+        if let Some(mut proxy_ui_entity_mut) = world.get_entity_mut(proxy_ui_entity) {
+            was_camera_local = proxy_ui_entity_mut
+                .get::<ProxyUiNodeState>()
+                .is_some_and(|state| state.get_space() == &ProxyUiSpace::CameraLocal);
+            proxy_ui_entity_mut.remove::<(ProxyUiTarget, ProxyUiNodeState)>();
+            render_target = proxy_ui_entity_mut.take::<ProxyUiRenderTarget>();
+        }
+        if let Some(mut proxied_target_entity_mut) = world.get_entity_mut(proxied_target_entity) {
+            let was_offscreen = proxied_target_entity_mut.contains::<ProxyUiCameraOffscreen>();
+            proxied_target_entity_mut.remove::<(
+                ProxyUiProxied,
+                ProxyUiNodeState,
+                ProxyUiInteraction,
+                ProxyUiCameraOffscreen,
+            )>();
+            // camera local targets were parented to the camera by us
+            if was_camera_local {
+                proxied_target_entity_mut.remove_parent();
+            }
+            // proxied cameras render to the whole window again
+            if let Some(mut camera) = proxied_target_entity_mut.get_mut::<Camera>() {
+                camera.viewport = None;
+                if was_offscreen {
+                    camera.is_active = true;
+                }
+            }
+        }
+        // render texture cameras, images and layers were created by us
+        if let Some(render_target) = render_target {
+            render_target.release(proxy_ui_entity, world);
+        }
+    }
+}
+
+pub trait ProxyUiCommandsExt {
+    /// removes the link between a proxy ui node and its proxied target,
+    /// `entity` can be either side of the link
+    fn unlink_proxy_ui(&mut self, entity: Entity);
+}
+
+impl ProxyUiCommandsExt for Commands<'_, '_> {
+    fn unlink_proxy_ui(&mut self, entity: Entity) {
+        self.add(UnlinkProxyUi { entity });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::window::PrimaryWindow;
+
+    use crate::system::proxy_ui_added::proxy_ui_added;
+    use crate::system::proxy_ui_camera::proxy_ui_camera;
+    use crate::system::proxy_ui_update::proxy_ui_update;
+    use crate::test::lib::*;
+
+    use super::*;
+
+    fn setup_linked_app() -> (App, Entity, Entity) {
+        let mut app = setup_test_app();
+        let proxied_target_entity = app.world.spawn(DummyComponent).id();
+        let proxy_ui_entity = app.world.spawn(ProxyUi::proxy(proxied_target_entity)).id();
+        app.world.run_system_once(proxy_ui_added);
+        app.world.run_system_once(proxy_ui_update);
+        assert!(app
+            .world
+            .entity(proxied_target_entity)
+            .contains::<ProxyUiNodeState>());
+        (app, proxy_ui_entity, proxied_target_entity)
+    }
+
+    fn unlink(app: &mut App, entity: Entity) {
+        app.world
+            .run_system_once(move |mut commands: Commands| commands.unlink_proxy_ui(entity));
+    }
+
+    fn assert_unlinked(app: &App, proxy_ui_entity: Entity, proxied_target_entity: Entity) {
+        let proxy_ui_entity_map = app.world.resource::<ProxyUiEntityMap>();
+        assert!(!proxy_ui_entity_map.contains_proxy(&proxy_ui_entity));
+        assert!(!proxy_ui_entity_map.is_proxied(&proxied_target_entity));
+        let proxy_ui_entity_ref = app.world.entity(proxy_ui_entity);
+        assert!(!proxy_ui_entity_ref.contains::<ProxyUiTarget>());
+        assert!(!proxy_ui_entity_ref.contains::<ProxyUiNodeState>());
+        let proxied_target_entity_ref = app.world.entity(proxied_target_entity);
+        assert!(!proxied_target_entity_ref.contains::<ProxyUiProxied>());
+        assert!(!proxied_target_entity_ref.contains::<ProxyUiNodeState>());
+    }
+
+    #[test]
+    fn test_unlink_by_proxy_ui_entity() {
+        let (mut app, proxy_ui_entity, proxied_target_entity) = setup_linked_app();
+        assert!(app
+            .world
+            .resource::<ProxyUiEntityMap>()
+            .contains_proxy(&proxy_ui_entity));
+        unlink(&mut app, proxy_ui_entity);
+        assert_unlinked(&app, proxy_ui_entity, proxied_target_entity);
+    }
+
+    #[test]
+    fn test_unlink_by_proxied_target_entity() {
+        let (mut app, proxy_ui_entity, proxied_target_entity) = setup_linked_app();
+        unlink(&mut app, proxied_target_entity);
+        assert_unlinked(&app, proxy_ui_entity, proxied_target_entity);
+    }
+
+    #[test]
+    fn test_unlink_without_map_entry() {
+        let (mut app, proxy_ui_entity, proxied_target_entity) = setup_linked_app();
+        app.world
+            .resource_mut::<ProxyUiEntityMap>()
+            .remove_by_proxy_entity(&proxy_ui_entity);
+        unlink(&mut app, proxied_target_entity);
+        assert_unlinked(&app, proxy_ui_entity, proxied_target_entity);
+    }
+
+    #[test]
+    fn test_unlink_despawned_proxied_target() {
+        let (mut app, proxy_ui_entity, proxied_target_entity) = setup_linked_app();
+        app.world.despawn(proxied_target_entity);
+        unlink(&mut app, proxy_ui_entity);
+        let proxy_ui_entity_map = app.world.resource::<ProxyUiEntityMap>();
+        assert!(!proxy_ui_entity_map.contains_proxy(&proxy_ui_entity));
+        assert!(!app
+            .world
+            .entity(proxy_ui_entity)
+            .contains::<ProxyUiTarget>());
+    }
+
+    #[test]
+    fn test_unlink_camera() {
+        let mut app = setup_test_app();
+        let camera_entity = app.world.spawn(Camera2dBundle::default()).id();
+        let proxy_ui_entity = app
+            .world
+            .spawn((
+                ProxyUi::proxy(camera_entity),
+                NodeBundle {
+                    node: sized_node(Vec2::splat(100.0)),
+                    ..default()
+                },
+            ))
+            .id();
+        app.world.run_system_once(proxy_ui_added);
+        app.world.run_system_once(proxy_ui_update);
+        app.world.run_system_once(proxy_ui_camera);
+        assert!(app
+            .world
+            .get::<Camera>(camera_entity)
+            .unwrap()
+            .viewport
+            .is_some());
+        unlink(&mut app, proxy_ui_entity);
+        assert!(app
+            .world
+            .get::<Camera>(camera_entity)
+            .unwrap()
+            .viewport
+            .is_none());
+    }
+
+    #[test]
+    fn test_unlink_offscreen_camera() {
+        let mut app = setup_test_app();
+        app.world.spawn((Window::default(), PrimaryWindow));
+        let camera_entity = app.world.spawn(Camera2dBundle::default()).id();
+        let proxy_ui_entity = app
+            .world
+            .spawn((
+                ProxyUi::proxy(camera_entity),
+                NodeBundle {
+                    node: sized_node(Vec2::splat(100.0)),
+                    global_transform: GlobalTransform::from_xyz(3000.0, 3000.0, 0.0),
+                    ..default()
+                },
+            ))
+            .id();
+        app.world.run_system_once(proxy_ui_added);
+        app.world.run_system_once(proxy_ui_update);
+        app.world.run_system_once(proxy_ui_camera);
+        assert!(!app.world.get::<Camera>(camera_entity).unwrap().is_active);
+        unlink(&mut app, proxy_ui_entity);
+        assert!(app.world.get::<Camera>(camera_entity).unwrap().is_active);
+        assert!(!app
+            .world
+            .entity(camera_entity)
+            .contains::<ProxyUiCameraOffscreen>());
+    }
+
+    #[test]
+    fn test_unlink_unlinked_entity() {
+        let (mut app, proxy_ui_entity, proxied_target_entity) = setup_linked_app();
+        let unlinked_entity = app.world.spawn(DummyComponent).id();
+        unlink(&mut app, unlinked_entity);
+        unlink(&mut app, Entity::from_raw(1234));
+        assert!(app
+            .world
+            .resource::<ProxyUiEntityMap>()
+            .contains_proxy(&proxy_ui_entity));
+        assert!(app
+            .world
+            .entity(proxied_target_entity)
+            .contains::<ProxyUiProxied>());
+    }
+}
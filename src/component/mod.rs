@@ -1,9 +1,47 @@
+pub use offscreen_indicator::*;
 pub use proxy_ui::*;
+pub use proxy_ui_anchor::*;
+pub use proxy_ui_box::*;
+pub use proxy_ui_camera_framing::*;
+pub(crate) use proxy_ui_camera_offscreen::*;
+pub use proxy_ui_clip::*;
+pub use proxy_ui_fit::*;
+pub use proxy_ui_interaction::*;
+pub use proxy_ui_mirror::*;
 pub use proxy_ui_node_state::*;
+pub use proxy_ui_paused::*;
+pub(crate) use proxy_ui_pending::*;
+pub use proxy_ui_pixel_snap::*;
+pub use proxy_ui_priority::*;
 pub use proxy_ui_proxied::*;
+pub use proxy_ui_quad::*;
+pub use proxy_ui_render_texture::*;
+pub use proxy_ui_space::*;
 pub use proxy_ui_target::*;
+#[cfg(feature = "bevy_ecs_tilemap")]
+pub use proxy_ui_tilemap::*;
+pub use world_proxy_ui::*;
 
+pub mod offscreen_indicator;
 pub mod proxy_ui;
+pub mod proxy_ui_anchor;
+pub mod proxy_ui_box;
+pub mod proxy_ui_camera_framing;
+pub(crate) mod proxy_ui_camera_offscreen;
+pub mod proxy_ui_clip;
+pub mod proxy_ui_fit;
+pub mod proxy_ui_interaction;
+pub mod proxy_ui_mirror;
 pub mod proxy_ui_node_state;
+pub mod proxy_ui_paused;
+pub(crate) mod proxy_ui_pending;
+pub mod proxy_ui_pixel_snap;
+pub mod proxy_ui_priority;
 pub mod proxy_ui_proxied;
+pub mod proxy_ui_quad;
+pub mod proxy_ui_render_texture;
+pub mod proxy_ui_space;
 pub mod proxy_ui_target;
+#[cfg(feature = "bevy_ecs_tilemap")]
+pub mod proxy_ui_tilemap;
+pub mod world_proxy_ui;
@@ -0,0 +1,69 @@
+use bevy::prelude::*;
+
+/// Keeps a `WorldProxyUi` node on screen, clamped to the camera viewport minus `margin` ui pixels
+/// while the target is off screen, see `OffscreenIndicatorEvent`
+#[derive(Debug, Default, Clone, Copy, PartialEq, Component, Reflect)]
+pub struct OffscreenIndicator {
+    pub margin: f32,
+    /// `None` until the indicator is first clamped
+    pub(crate) on_screen: Option<bool>,
+    pub(crate) angle: f32,
+}
+
+impl OffscreenIndicator {
+    pub fn new(margin: f32) -> Self {
+        Self {
+            margin,
+            ..default()
+        }
+    }
+    pub fn is_on_screen(&self) -> bool {
+        self.on_screen.unwrap_or_default()
+    }
+    /// the counter-clockwise angle on screen from the viewport center to the target in radians,
+    /// 0 points right
+    pub fn get_angle(&self) -> f32 {
+        self.angle
+    }
+    /// returns the clamped position, the angle and whether `position` is on screen,
+    /// all in ui space
+    pub fn clamp(&self, viewport_rect: Rect, position: Vec2) -> (Vec2, f32, bool) {
+        let center = viewport_rect.center();
+        let half_size = (viewport_rect.half_size() - Vec2::splat(self.margin)).max(Vec2::ZERO);
+        let direction = position - center;
+        // ui is y down
+        let angle = (center.y - position.y).atan2(direction.x);
+        let inset_rect = Rect::from_center_half_size(center, half_size);
+        if inset_rect.contains(position) {
+            return (position, angle, true);
+        }
+        // move along the direction to the target until the first edge of the inset rect
+        let scale = (half_size / direction.abs()).min_element();
+        (center + direction * scale, angle, false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::{FRAC_PI_2, PI};
+
+    use super::*;
+
+    #[test]
+    fn test_clamp() {
+        let indicator = OffscreenIndicator::new(10.0);
+        let viewport_rect = Rect::new(0.0, 0.0, 200.0, 100.0);
+        assert_eq!(
+            indicator.clamp(viewport_rect, Vec2::new(150.0, 50.0)),
+            (Vec2::new(150.0, 50.0), 0.0, true)
+        );
+        assert_eq!(
+            indicator.clamp(viewport_rect, Vec2::new(-100.0, 50.0)),
+            (Vec2::new(10.0, 50.0), PI, false)
+        );
+        assert_eq!(
+            indicator.clamp(viewport_rect, Vec2::new(100.0, -500.0)),
+            (Vec2::new(100.0, 10.0), FRAC_PI_2, false)
+        );
+    }
+}
@@ -1,12 +1,43 @@
 use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+
+use crate::prelude::*;
 
 #[derive(Debug, Component, Reflect)]
 pub struct ProxyUi {
     pub(crate) target_entity: Entity,
+    pub(crate) error_policy: Option<ProxyUiErrorPolicy>,
+    pub(crate) mirror: bool,
+    pub(crate) focus_policy: Option<FocusPolicy>,
 }
 
 impl ProxyUi {
     pub fn proxy(target_entity: Entity) -> Self {
-        Self { target_entity }
+        Self {
+            target_entity,
+            error_policy: None,
+            mirror: false,
+            focus_policy: None,
+        }
+    }
+    /// links to another ui node instead of a world entity,
+    /// the target node's position and size follow this node, see `ProxyUiMirror`
+    pub fn mirror(target_entity: Entity) -> Self {
+        Self {
+            mirror: true,
+            ..Self::proxy(target_entity)
+        }
+    }
+    /// overrides the `ProxyUiErrorPolicy` resource for this link
+    pub fn with_error_policy(mut self, error_policy: ProxyUiErrorPolicy) -> Self {
+        self.error_policy = Some(error_policy);
+        self
+    }
+    /// makes the proxy ui node interactive, `ProxyUiInteraction` is then mirrored onto the target.
+    ///
+    /// `FocusPolicy::Block` captures clicks, `FocusPolicy::Pass` lets them through to nodes below
+    pub fn with_focus_policy(mut self, focus_policy: FocusPolicy) -> Self {
+        self.focus_policy = Some(focus_policy);
+        self
     }
 }
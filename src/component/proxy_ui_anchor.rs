@@ -0,0 +1,98 @@
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+
+/// Where a proxy target is placed inside its proxy node, add this to the proxied target.
+///
+/// The `target` point of the target's native bounds is placed on the `node` point of the node's
+/// rect, then `pixel_offset` (ui pixels, y down) and `world_offset` (world units, y up) are added
+#[derive(Debug, Default, Clone, Copy, PartialEq, Component, Reflect)]
+pub struct ProxyUiAnchor {
+    pub target: Anchor,
+    pub node: Anchor,
+    pub pixel_offset: Vec2,
+    pub world_offset: Vec2,
+}
+
+impl ProxyUiAnchor {
+    /// places the same point of the target on the same point of the node
+    pub fn new(anchor: Anchor) -> Self {
+        Self {
+            target: anchor,
+            node: anchor,
+            ..default()
+        }
+    }
+    pub fn with_pixel_offset(mut self, pixel_offset: Vec2) -> Self {
+        self.pixel_offset = pixel_offset;
+        self
+    }
+    pub fn with_world_offset(mut self, world_offset: Vec2) -> Self {
+        self.world_offset = world_offset;
+        self
+    }
+    /// returns the anchored point of the node's world rect
+    pub fn node_point(&self, rect: Rect) -> Vec2 {
+        rect.center() + self.node.as_vec() * rect.size()
+    }
+    /// returns the anchored point of the target's native bounds in its local space
+    pub fn target_point(&self, native_bounds: Rect) -> Vec2 {
+        native_bounds.center() + self.target.as_vec() * native_bounds.size()
+    }
+    /// returns the combined offset in world units,
+    /// `pixel_to_world` is the size of one ui pixel in world units
+    pub fn offset(&self, pixel_to_world: Vec2) -> Vec2 {
+        // ui is y down while world is y up
+        self.pixel_offset * Vec2::new(pixel_to_world.x, -pixel_to_world.y) + self.world_offset
+    }
+    /// returns the transform that places `native_bounds` (in the target's local space)
+    /// scaled by `scale` inside `rect` (in world space)
+    pub fn place(
+        &self,
+        native_bounds: Rect,
+        rect: Rect,
+        scale: Vec2,
+        pixel_to_world: Vec2,
+        z: f32,
+    ) -> Transform {
+        let translation = self.node_point(rect) - self.target_point(native_bounds) * scale
+            + self.offset(pixel_to_world);
+        Transform {
+            translation: translation.extend(z),
+            scale: scale.extend(1.0),
+            ..default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_proxy_ui_anchor_place() {
+        // tilemap like bounds with the origin in the bottom left corner
+        let native_bounds = Rect::new(0.0, 0.0, 100.0, 50.0);
+        let rect = Rect::new(-100.0, -100.0, 100.0, 100.0);
+
+        let centered =
+            ProxyUiAnchor::default().place(native_bounds, rect, Vec2::ONE, Vec2::ONE, 0.0);
+        assert_eq!(centered.translation, Vec3::new(-50.0, -25.0, 0.0));
+
+        let top_left = ProxyUiAnchor::new(Anchor::TopLeft)
+            .with_pixel_offset(Vec2::new(5.0, 5.0))
+            .place(native_bounds, rect, Vec2::ONE, Vec2::splat(2.0), 0.0);
+        assert_eq!(
+            top_left.transform_point(Vec3::new(0.0, 50.0, 0.0)),
+            Vec3::new(-90.0, 90.0, 0.0)
+        );
+
+        let custom = ProxyUiAnchor {
+            target: Anchor::BottomLeft,
+            node: Anchor::Custom(Vec2::new(0.25, 0.0)),
+            world_offset: Vec2::new(0.0, 10.0),
+            ..default()
+        }
+        .place(native_bounds, rect, Vec2::splat(2.0), Vec2::ONE, 1.0);
+        assert_eq!(custom.translation, Vec3::new(50.0, 10.0, 1.0));
+    }
+}
@@ -0,0 +1,39 @@
+use bevy::prelude::*;
+
+/// Which box of the proxy node the link targets, add this to the proxy ui node.
+///
+/// Defaults to the border box which is the full rect of the node
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Component, Reflect)]
+pub enum ProxyUiBox {
+    #[default]
+    BorderBox,
+    /// the border box minus the border
+    PaddingBox,
+    /// the padding box minus the padding
+    ContentBox,
+}
+
+/// The border, padding and content boxes of a proxy node in a single space
+#[derive(Debug, Default, Clone, Copy, PartialEq, Reflect)]
+pub struct ProxyUiBoxRects {
+    pub border_box: Rect,
+    pub padding_box: Rect,
+    pub content_box: Rect,
+}
+
+impl ProxyUiBoxRects {
+    pub fn get(&self, proxy_ui_box: ProxyUiBox) -> Rect {
+        match proxy_ui_box {
+            ProxyUiBox::BorderBox => self.border_box,
+            ProxyUiBox::PaddingBox => self.padding_box,
+            ProxyUiBox::ContentBox => self.content_box,
+        }
+    }
+    pub fn map(&self, f: impl Fn(Rect) -> Rect) -> Self {
+        Self {
+            border_box: f(self.border_box),
+            padding_box: f(self.padding_box),
+            content_box: f(self.content_box),
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use bevy::prelude::*;
+use bevy::render::camera::ScalingMode;
+
+/// Frames a world region with a proxied orthographic camera, the camera is centered on `region`
+/// and its projection scaled so the region fits the proxy ui node
+#[derive(Debug, Default, Clone, Copy, PartialEq, Component, Reflect)]
+pub struct ProxyUiCameraFraming {
+    pub region: Rect,
+}
+
+impl ProxyUiCameraFraming {
+    pub fn new(region: Rect) -> Self {
+        Self { region }
+    }
+    /// returns the projection scale that fits the region into a viewport of `logical_size`
+    pub fn scale(&self, projection: &OrthographicProjection, logical_size: Vec2) -> Option<f32> {
+        let base_size = match projection.scaling_mode {
+            ScalingMode::WindowSize(pixels_per_unit) => logical_size / pixels_per_unit,
+            // the area is only updated by bevy, assume it matches the current viewport
+            _ => projection.area.size() / projection.scale,
+        };
+        if !base_size.cmpgt(Vec2::ZERO).all() || self.region.is_empty() {
+            return None;
+        }
+        Some((self.region.size() / base_size).max_element())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scale() {
+        let framing = ProxyUiCameraFraming::new(Rect::new(0.0, 0.0, 400.0, 100.0));
+        let projection = OrthographicProjection::default();
+        assert_eq!(
+            framing.scale(&projection, Vec2::new(200.0, 200.0)),
+            Some(2.0)
+        );
+        assert_eq!(framing.scale(&projection, Vec2::ZERO), None);
+    }
+}
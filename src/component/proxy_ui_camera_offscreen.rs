@@ -0,0 +1,6 @@
+use bevy::prelude::*;
+
+/// Marks a proxied camera that was deactivated because its proxy node is outside of the render
+/// target, it's activated again once the node is back or the link is removed
+#[derive(Debug, Default, Component)]
+pub(crate) struct ProxyUiCameraOffscreen;
@@ -0,0 +1,25 @@
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+
+/// How the proxied target reacts to the node being clipped by a parent with `Overflow::clip()`,
+/// add this to the proxy ui node
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Component, Reflect)]
+pub enum ProxyUiClip {
+    /// the clip rect is only reported in `ProxyUiNodeState`
+    #[default]
+    Ignore,
+    /// hides the proxied target when the node is fully clipped
+    Hide,
+    /// hides the proxied target when the node is fully clipped and crops a `Sprite` when it is
+    /// partially clipped, assuming the sprite covers the node's world rect
+    Crop,
+}
+
+/// The visibility and sprite settings of a cropped or hidden proxied target before clipping was
+/// applied
+#[derive(Debug, Default, Component)]
+pub(crate) struct ProxyUiClipped {
+    /// the visibility of the target before it was hidden
+    pub(crate) hidden: Option<Visibility>,
+    pub(crate) sprite: Option<(Option<Rect>, Option<Vec2>, Anchor)>,
+}
@@ -0,0 +1,179 @@
+use bevy::prelude::*;
+
+use crate::prelude::*;
+
+/// How a proxy target with a known native size is scaled into its proxy node
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum ProxyUiFit {
+    /// scales each axis independently to fill the node, ignoring the aspect ratio
+    #[default]
+    Stretch,
+    /// scales uniformly so the whole target fits inside the node
+    Contain,
+    /// scales uniformly so the target covers the whole node, overflowing on one axis
+    Cover,
+    /// keeps the native size of the target
+    None,
+}
+
+impl ProxyUiFit {
+    /// returns the scale that maps `native_size` into `available_size`
+    pub fn scale(&self, native_size: Vec2, available_size: Vec2) -> Vec2 {
+        // guard against zero sized targets, scaling them is meaningless
+        if native_size.x <= 0.0 || native_size.y <= 0.0 {
+            return Vec2::ONE;
+        }
+        let scale = available_size / native_size;
+        match self {
+            ProxyUiFit::Stretch => scale,
+            ProxyUiFit::Contain => Vec2::splat(scale.min_element()),
+            ProxyUiFit::Cover => Vec2::splat(scale.max_element()),
+            ProxyUiFit::None => Vec2::ONE,
+        }
+    }
+    /// returns the transform that places `native_bounds` (in the target's local space)
+    /// inside the world rect of the node according to `anchor`,
+    /// limited to whole physical pixel multiples with `ProxyUiPixelSnap::integer_scale`
+    pub fn transform(
+        &self,
+        native_bounds: Rect,
+        node_state: &ProxyUiNodeState,
+        anchor: &ProxyUiAnchor,
+        z: f32,
+    ) -> Transform {
+        let rect = *node_state.get_world_rect();
+        let scale = node_state.get_pixel_snap().apply_scale(
+            self.scale(native_bounds.size(), rect.size()),
+            node_state.get_physical_pixel_to_world(),
+        );
+        anchor.place(
+            native_bounds,
+            rect,
+            scale,
+            node_state.get_pixel_to_world(),
+            z,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::render::camera::CameraProjection;
+    use bevy::sprite::Anchor;
+
+    use crate::system::proxy_ui_added::proxy_ui_added;
+    use crate::system::proxy_ui_update::proxy_ui_update;
+    use crate::test::lib::*;
+
+    use super::*;
+
+    #[test]
+    fn test_fit_scale() {
+        let native = Vec2::new(100.0, 50.0);
+        let available = Vec2::new(200.0, 200.0);
+        assert_eq!(
+            ProxyUiFit::Stretch.scale(native, available),
+            Vec2::new(2.0, 4.0)
+        );
+        assert_eq!(
+            ProxyUiFit::Contain.scale(native, available),
+            Vec2::splat(2.0)
+        );
+        assert_eq!(ProxyUiFit::Cover.scale(native, available), Vec2::splat(4.0));
+        assert_eq!(ProxyUiFit::None.scale(native, available), Vec2::ONE);
+        assert_eq!(ProxyUiFit::Stretch.scale(Vec2::ZERO, available), Vec2::ONE);
+    }
+
+    /// returns the state of a node of `size` at the center of the 1280x720 window,
+    /// with a camera zoomed in by `1 / projection_scale`
+    fn node_state(
+        size: Vec2,
+        pixel_snap: ProxyUiPixelSnap,
+        projection_scale: f32,
+    ) -> ProxyUiNodeState {
+        let mut app = setup_test_app();
+        let camera_entity = spawn_window_camera(&mut app);
+        let mut projection = app
+            .world
+            .get_mut::<OrthographicProjection>(camera_entity)
+            .unwrap();
+        projection.scale = projection_scale;
+        projection.update(1280.0, 720.0);
+        let target_entity = app.world.spawn(DummyComponent).id();
+        app.world.spawn((
+            ProxyUi::proxy(target_entity),
+            pixel_snap,
+            NodeBundle {
+                node: sized_node(size),
+                global_transform: GlobalTransform::from_xyz(640.0, 360.0, 0.0),
+                ..default()
+            },
+        ));
+        app.world.run_system_once(proxy_ui_added);
+        app.world.run_system_once(proxy_ui_update);
+        app.world
+            .get::<ProxyUiNodeState>(target_entity)
+            .unwrap()
+            .clone()
+    }
+
+    #[test]
+    fn test_fit_transform() {
+        let native_bounds = Rect::new(0.0, 0.0, 100.0, 50.0);
+        // the world rect is (-100, -100) (100, 100)
+        let node_state = node_state(Vec2::splat(200.0), ProxyUiPixelSnap::default(), 1.0);
+        let transform = ProxyUiFit::Contain.transform(
+            native_bounds,
+            &node_state,
+            &ProxyUiAnchor::default(),
+            1.0,
+        );
+        assert_eq!(transform.scale, Vec3::new(2.0, 2.0, 1.0));
+        assert_eq!(transform.translation, Vec3::new(-100.0, -50.0, 1.0));
+        assert_eq!(
+            transform.transform_point(native_bounds.max.extend(0.0)),
+            Vec3::new(100.0, 50.0, 1.0)
+        );
+
+        let transform = ProxyUiFit::Contain.transform(
+            native_bounds,
+            &node_state,
+            &ProxyUiAnchor::new(Anchor::TopRight),
+            1.0,
+        );
+        assert_eq!(transform.translation, Vec3::new(-100.0, 0.0, 1.0));
+        assert_eq!(
+            transform.transform_point(native_bounds.max.extend(0.0)),
+            Vec3::new(100.0, 100.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn test_fit_transform_integer_scale() {
+        let native_bounds = Rect::new(0.0, 0.0, 16.0, 16.0);
+        let integer_scale = ProxyUiPixelSnap {
+            integer_scale: true,
+            ..default()
+        };
+        // a 90x90 node is 45x45 world units with the camera zoomed in twice,
+        // 16 native pixels fit 2.8125 times which is 5.625 physical pixels per native pixel
+        let fit = |pixel_snap| {
+            ProxyUiFit::Contain.transform(
+                native_bounds,
+                &node_state(Vec2::splat(90.0), pixel_snap, 0.5),
+                &ProxyUiAnchor::default(),
+                0.0,
+            )
+        };
+        assert!(fit(ProxyUiPixelSnap::default())
+            .scale
+            .abs_diff_eq(Vec3::new(2.8125, 2.8125, 1.0), 1e-4));
+        // rounded down to 5 physical pixels, 2.5 world units, and centered in the node
+        let transform = fit(integer_scale);
+        assert!(transform.scale.abs_diff_eq(Vec3::new(2.5, 2.5, 1.0), 1e-4));
+        assert!(transform
+            .translation
+            .abs_diff_eq(Vec3::new(-20.0, -20.0, 0.0), 1e-3));
+    }
+}
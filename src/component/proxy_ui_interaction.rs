@@ -0,0 +1,7 @@
+use bevy::prelude::*;
+
+/// The `Interaction` of the proxy ui node, mirrored onto the proxied target.
+///
+/// The proxy ui node needs an `Interaction`, see `ProxyUi::with_focus_policy`
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deref, Component, Reflect)]
+pub struct ProxyUiInteraction(pub Interaction);
@@ -0,0 +1,31 @@
+use bevy::prelude::*;
+
+/// Marks a ui to ui link created with `ProxyUi::mirror`, lives on the proxy ui node.
+///
+/// The target node is positioned absolutely at the ui rect of the proxy node, mapped by
+/// `scale` and `offset` (in ui pixels) so it can follow along in another window or
+/// `TargetCamera` of a different size. The target node is expected to be a root node
+#[derive(Debug, Clone, Copy, PartialEq, Component, Reflect)]
+pub struct ProxyUiMirror {
+    pub scale: Vec2,
+    pub offset: Vec2,
+}
+
+impl Default for ProxyUiMirror {
+    fn default() -> Self {
+        Self {
+            scale: Vec2::ONE,
+            offset: Vec2::ZERO,
+        }
+    }
+}
+
+impl ProxyUiMirror {
+    /// returns the ui rect of the target node for the ui rect of the proxy node
+    pub fn map_rect(&self, ui_rect: Rect) -> Rect {
+        Rect {
+            min: ui_rect.min * self.scale + self.offset,
+            max: ui_rect.max * self.scale + self.offset,
+        }
+    }
+}
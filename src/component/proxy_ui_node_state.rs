@@ -1,24 +1,128 @@
+use bevy::ecs::system::EntityCommands;
 use bevy::prelude::*;
 
+use crate::prelude::*;
+use crate::util::ui::snap_to_pixel_grid;
+
 #[derive(Debug, Clone, PartialEq, Component, Reflect)]
 pub struct ProxyUiNodeState {
+    /// the rect of the targeted `ProxyUiBox` in ui space, before `UiScale` is applied
     pub(crate) ui_rect: Rect,
+    /// the rect of the targeted `ProxyUiBox` in logical window pixels, `UiScale` applied
+    pub(crate) logical_rect: Rect,
+    /// the rect of the targeted `ProxyUiBox` in physical window pixels,
+    /// `UiScale` and the window scale factor applied
+    pub(crate) physical_rect: Rect,
+    /// the unrotated rect of the targeted `ProxyUiBox` in world space
     pub(crate) world_rect: Rect,
+    /// the oriented quad of the targeted `ProxyUiBox` in world space
+    pub(crate) world_quad: ProxyUiQuad,
+    pub(crate) target_box: ProxyUiBox,
+    pub(crate) ui_boxes: ProxyUiBoxRects,
+    pub(crate) world_boxes: ProxyUiBoxRects,
+    /// the clip rect from `CalculatedClip` in ui space, if any parent clips the node
+    pub(crate) ui_clip: Option<Rect>,
+    /// the clip rect in world space, unrotated
+    pub(crate) world_clip: Option<Rect>,
+    /// the index of the node in `UiStack`, higher is on top
+    pub(crate) stack_index: u32,
+    /// the z mapped from `stack_index` when `ProxyUiZRange` is present
+    pub(crate) world_z: Option<f32>,
     pub(crate) global_transform: GlobalTransform,
     pub(crate) transform: Transform,
     pub(crate) style: Style,
     pub(crate) ui_scale: f32,
+    pub(crate) scale_factor: f32,
     pub(crate) visibility: Visibility,
     pub(crate) view_visibility: ViewVisibility,
+    pub(crate) pixel_snap: ProxyUiPixelSnap,
+    pub(crate) space: ProxyUiSpace,
+    pub(crate) clip: ProxyUiClip,
 }
 
 impl ProxyUiNodeState {
     pub fn get_ui_rect(&self) -> &Rect {
         &self.ui_rect
     }
+    /// returns the ui rect the world quad was built from,
+    /// snapped to the pixel grid if `ProxyUiPixelSnap::snap_to_pixels` is set
+    pub fn get_snapped_ui_rect(&self) -> Rect {
+        if self.pixel_snap.snap_to_pixels {
+            snap_to_pixel_grid(self.ui_rect, self.scale_factor * self.ui_scale)
+        } else {
+            self.ui_rect
+        }
+    }
+    pub fn get_logical_rect(&self) -> &Rect {
+        &self.logical_rect
+    }
+    pub fn get_physical_rect(&self) -> &Rect {
+        &self.physical_rect
+    }
     pub fn get_world_rect(&self) -> &Rect {
         &self.world_rect
     }
+    /// returns the transform of the center of the world rect with the node's rotation,
+    /// at the z from `ProxyUiZRange` if present
+    pub fn get_world_transform(&self) -> Transform {
+        Transform::from_translation(self.world_rect.center().extend(self.world_z.unwrap_or(0.0)))
+            .with_rotation(Quat::from_rotation_z(self.get_world_rotation()))
+    }
+    pub fn get_world_quad(&self) -> &ProxyUiQuad {
+        &self.world_quad
+    }
+    /// returns the counter-clockwise rotation of the node in world space in radians
+    pub fn get_world_rotation(&self) -> f32 {
+        self.world_quad.rotation()
+    }
+    /// rotates `transform` around `pivot` by the rotation of the node,
+    /// use this to carry the node's rotation over to a transform placed inside the world rect
+    pub fn orient(&self, transform: Transform, pivot: Vec2) -> Transform {
+        let pivot = pivot.extend(0.0);
+        let rotation = Quat::from_rotation_z(self.get_world_rotation());
+        Transform {
+            translation: pivot + rotation * (transform.translation - pivot),
+            rotation: rotation * transform.rotation,
+            scale: transform.scale,
+        }
+    }
+    pub fn get_target_box(&self) -> &ProxyUiBox {
+        &self.target_box
+    }
+    /// border, padding and content box rects in ui space
+    pub fn get_ui_boxes(&self) -> &ProxyUiBoxRects {
+        &self.ui_boxes
+    }
+    /// unrotated border, padding and content box rects in world space
+    pub fn get_world_boxes(&self) -> &ProxyUiBoxRects {
+        &self.world_boxes
+    }
+    pub fn get_ui_clip(&self) -> Option<&Rect> {
+        self.ui_clip.as_ref()
+    }
+    pub fn get_world_clip(&self) -> Option<&Rect> {
+        self.world_clip.as_ref()
+    }
+    /// returns the part of the world rect inside the world clip rect,
+    /// or `None` when the node is fully clipped
+    pub fn get_visible_world_rect(&self) -> Option<Rect> {
+        let visible_rect = match self.world_clip {
+            Some(world_clip) => self.world_rect.intersect(world_clip),
+            None => self.world_rect,
+        };
+        if visible_rect.is_empty() && !self.world_rect.is_empty() {
+            None
+        } else {
+            Some(visible_rect)
+        }
+    }
+    pub fn get_stack_index(&self) -> u32 {
+        self.stack_index
+    }
+    /// the z mapped from the node's stacking order, `None` without `ProxyUiZRange`
+    pub fn get_world_z(&self) -> Option<f32> {
+        self.world_z
+    }
     pub fn get_global_transform(&self) -> &GlobalTransform {
         &self.global_transform
     }
@@ -31,12 +135,54 @@ impl ProxyUiNodeState {
     pub fn get_ui_scale(&self) -> &f32 {
         &self.ui_scale
     }
+    /// the scale factor of the primary window
+    pub fn get_scale_factor(&self) -> &f32 {
+        &self.scale_factor
+    }
     pub fn get_visibility(&self) -> &Visibility {
         &self.visibility
     }
     pub fn get_view_visibility(&self) -> &ViewVisibility {
         &self.view_visibility
     }
+    /// returns the size of one ui pixel in world units, useful for `ProxyUiAnchor::place`
+    pub fn get_pixel_to_world(&self) -> Vec2 {
+        let ui_size = self.ui_rect.size();
+        if ui_size.cmpgt(Vec2::ZERO).all() {
+            self.world_rect.size() / ui_size
+        } else {
+            Vec2::ONE
+        }
+    }
+    /// returns the size of one physical pixel in world units,
+    /// with the window scale factor, `UiScale` and the camera projection resolved
+    pub fn get_physical_pixel_to_world(&self) -> Vec2 {
+        let physical_size = self.physical_rect.size();
+        if physical_size.cmpgt(Vec2::ZERO).all() {
+            self.world_rect.size() / physical_size
+        } else {
+            Vec2::ONE
+        }
+    }
+    pub fn get_pixel_snap(&self) -> &ProxyUiPixelSnap {
+        &self.pixel_snap
+    }
+    /// the space world rects and transforms are in
+    pub fn get_space(&self) -> &ProxyUiSpace {
+        &self.space
+    }
+    pub fn get_clip(&self) -> &ProxyUiClip {
+        &self.clip
+    }
+    /// inserts a transform computed from the world rect into the proxied target,
+    /// as a `GlobalTransform` in world space or as a `Transform` relative to the camera
+    pub fn insert_transform(&self, entity_commands: &mut EntityCommands, transform: Transform) {
+        match self.space {
+            // ignore the relative transform to the parent
+            ProxyUiSpace::World => entity_commands.insert(GlobalTransform::from(transform)),
+            ProxyUiSpace::CameraLocal => entity_commands.insert(transform),
+        };
+    }
     /// returns the computed visibility from ViewVisibility but represented as Visibility
     /// for inserting into proxy targets
     /// since visibility can be "Inherited" and inserting a copy of ViewVisibility doesn't
@@ -0,0 +1,5 @@
+use bevy::prelude::*;
+
+/// Pauses a link when added to the proxy ui node, the last `ProxyUiNodeState` is kept
+#[derive(Debug, Default, Clone, Copy, PartialEq, Component, Reflect)]
+pub struct ProxyUiPaused;
@@ -0,0 +1,7 @@
+use bevy::prelude::*;
+
+/// Marks a `ProxyUi` that is waiting for its target entity to appear
+#[derive(Debug, Default, Component)]
+pub(crate) struct ProxyUiPending {
+    pub(crate) attempts: u32,
+}
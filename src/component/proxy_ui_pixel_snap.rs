@@ -0,0 +1,34 @@
+use bevy::prelude::*;
+
+/// Pixel-art options for a proxy link, add this to the proxy ui node
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Component, Reflect)]
+pub struct ProxyUiPixelSnap {
+    /// snaps the rect used for the world conversion to the physical pixel grid
+    /// using the window scale factor and `UiScale`
+    pub snap_to_pixels: bool,
+    /// limits fit scaling to whole-number multiples of the target's native size,
+    /// the leftover space is centered inside the node
+    pub integer_scale: bool,
+}
+
+impl ProxyUiPixelSnap {
+    pub fn pixel_perfect() -> Self {
+        Self {
+            snap_to_pixels: true,
+            integer_scale: true,
+        }
+    }
+    /// returns the scale rounded down so every native pixel covers a whole number of physical
+    /// pixels if `integer_scale` is enabled
+    ///
+    /// `scale` maps native pixels to world units and `physical_pixel_to_world` is the size of
+    /// one physical pixel in world units, see `ProxyUiNodeState::get_physical_pixel_to_world`
+    pub fn apply_scale(&self, scale: Vec2, physical_pixel_to_world: Vec2) -> Vec2 {
+        if !self.integer_scale || physical_pixel_to_world.cmple(Vec2::ZERO).any() {
+            return scale;
+        }
+        // anything smaller than one physical pixel per native pixel can't be a whole-number multiple
+        let physical_scale = (scale / physical_pixel_to_world).floor().max(Vec2::ONE);
+        physical_scale * physical_pixel_to_world
+    }
+}
@@ -0,0 +1,11 @@
+use bevy::prelude::*;
+
+/// The priority of a `WorldProxyUi` label when decluttering, higher stays in place,
+/// see `ProxyUiDeclutter`
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Component, Reflect)]
+pub struct ProxyUiPriority(pub i32);
+
+/// Marks a `WorldProxyUi` label hidden by `ProxyUiDeclutter`, with its visibility from before
+/// it was hidden
+#[derive(Debug, Default, Component)]
+pub(crate) struct ProxyUiDeclutterHidden(pub(crate) Visibility);
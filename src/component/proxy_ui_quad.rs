@@ -0,0 +1,80 @@
+use bevy::math::Affine2;
+use bevy::prelude::*;
+
+/// An oriented quad in world space, keeps the rotation and scale of the ui node
+#[derive(Debug, Default, Clone, Copy, PartialEq, Reflect)]
+pub struct ProxyUiQuad {
+    /// top left, top right, bottom right and bottom left corners as seen on screen
+    pub corners: [Vec2; 4],
+    /// maps the unit square centered on the origin onto the quad
+    pub affine: Affine2,
+}
+
+impl ProxyUiQuad {
+    pub fn from_corners(corners: [Vec2; 4]) -> Self {
+        let [top_left, top_right, _bottom_right, bottom_left] = corners;
+        let center = corners.iter().sum::<Vec2>() / 4.0;
+        Self {
+            corners,
+            affine: Affine2::from_cols(top_right - top_left, top_left - bottom_left, center),
+        }
+    }
+    pub fn center(&self) -> Vec2 {
+        self.affine.translation
+    }
+    /// returns the unrotated size of the quad
+    pub fn size(&self) -> Vec2 {
+        Vec2::new(
+            self.affine.matrix2.x_axis.length(),
+            self.affine.matrix2.y_axis.length(),
+        )
+    }
+    /// returns the counter-clockwise rotation of the quad in radians
+    pub fn rotation(&self) -> f32 {
+        let x_axis = self.affine.matrix2.x_axis;
+        x_axis.y.atan2(x_axis.x)
+    }
+    /// returns the unrotated rect of the quad around its center
+    pub fn rect(&self) -> Rect {
+        Rect::from_center_size(self.center(), self.size())
+    }
+    /// returns the point at the normalized position in the quad, 0..1 from the top left corner
+    pub fn point_at(&self, normalized: Vec2) -> Vec2 {
+        self.affine
+            .transform_point2(Vec2::new(normalized.x - 0.5, 0.5 - normalized.y))
+    }
+    /// returns the axis aligned bounding rect of the quad
+    pub fn bounds(&self) -> Rect {
+        let [a, b, c, d] = self.corners;
+        Rect::from_corners(a.min(b).min(c).min(d), a.max(b).max(c).max(d))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use super::*;
+
+    #[test]
+    fn test_proxy_ui_quad() {
+        // 20x10 rect rotated a quarter turn counter-clockwise around its center
+        let quad = ProxyUiQuad::from_corners([
+            Vec2::new(5.0, -5.0),
+            Vec2::new(5.0, 15.0),
+            Vec2::new(15.0, 15.0),
+            Vec2::new(15.0, -5.0),
+        ]);
+        assert_eq!(quad.center(), Vec2::new(10.0, 5.0));
+        assert_eq!(quad.size(), Vec2::new(20.0, 10.0));
+        assert!((quad.rotation() - FRAC_PI_2).abs() < f32::EPSILON);
+        assert_eq!(quad.rect(), Rect::new(0.0, 0.0, 20.0, 10.0));
+        assert_eq!(quad.bounds(), Rect::new(5.0, -5.0, 15.0, 15.0));
+        assert_eq!(
+            quad.affine.transform_point2(Vec2::new(-0.5, 0.5)),
+            Vec2::new(5.0, -5.0)
+        );
+        assert_eq!(quad.point_at(Vec2::ZERO), Vec2::new(5.0, -5.0));
+        assert_eq!(quad.point_at(Vec2::ONE), Vec2::new(15.0, 15.0));
+    }
+}
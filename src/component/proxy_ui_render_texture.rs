@@ -0,0 +1,77 @@
+use bevy::prelude::*;
+use bevy::render::view::RenderLayers;
+use bevy::utils::HashMap;
+
+/// Renders the proxied target into an image shown by the proxy ui node, add this to the node.
+///
+/// A camera rendering only `layer` is spawned at `camera_offset` from the target, looking at it,
+/// and the target and its descendants are moved to `layer` until the link or this component is
+/// removed. The node's `BackgroundColor` tints the image, it's set to white while linked
+#[derive(Debug, Clone, Copy, PartialEq, Component, Reflect)]
+pub struct ProxyUiRenderTexture {
+    pub layer: u8,
+    pub camera_offset: Vec3,
+    pub clear_color: Color,
+}
+
+impl Default for ProxyUiRenderTexture {
+    fn default() -> Self {
+        Self {
+            layer: 1,
+            camera_offset: Vec3::new(0.0, 0.0, 5.0),
+            clear_color: Color::NONE,
+        }
+    }
+}
+
+impl ProxyUiRenderTexture {
+    pub fn new(layer: u8) -> Self {
+        Self { layer, ..default() }
+    }
+    pub fn with_camera_offset(mut self, camera_offset: Vec3) -> Self {
+        self.camera_offset = camera_offset;
+        self
+    }
+    pub fn with_clear_color(mut self, clear_color: Color) -> Self {
+        self.clear_color = clear_color;
+        self
+    }
+}
+
+/// The camera and image created for a `ProxyUiRenderTexture`, lives on the proxy ui node
+#[derive(Debug, Component)]
+pub(crate) struct ProxyUiRenderTarget {
+    pub(crate) camera_entity: Entity,
+    pub(crate) image: Handle<Image>,
+    /// the render layers of the target and its descendants before they were moved to the layer
+    pub(crate) previous_layers: HashMap<Entity, Option<RenderLayers>>,
+    /// the background color of the node before it was tinted white to show the image
+    pub(crate) previous_background_color: Option<BackgroundColor>,
+}
+
+impl ProxyUiRenderTarget {
+    /// despawns the camera, removes the image and restores the render layers and the node's
+    /// background color
+    pub(crate) fn release(self, proxy_ui_entity: Entity, world: &mut World) {
+        if let Some(mut proxy_ui_entity_mut) = world.get_entity_mut(proxy_ui_entity) {
+            proxy_ui_entity_mut.remove::<UiImage>();
+            match self.previous_background_color {
+                Some(background_color) => proxy_ui_entity_mut.insert(background_color),
+                None => proxy_ui_entity_mut.remove::<BackgroundColor>(),
+            };
+        }
+        for (entity, previous_layers) in self.previous_layers {
+            let Some(mut entity_mut) = world.get_entity_mut(entity) else {
+                continue;
+            };
+            match previous_layers {
+                Some(layers) => entity_mut.insert(layers),
+                None => entity_mut.remove::<RenderLayers>(),
+            };
+        }
+        world.despawn(self.camera_entity);
+        if let Some(mut images) = world.get_resource_mut::<Assets<Image>>() {
+            images.remove(&self.image);
+        }
+    }
+}
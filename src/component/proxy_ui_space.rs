@@ -0,0 +1,14 @@
+use bevy::prelude::*;
+
+/// The space the proxied target is placed in, add this to the proxy ui node
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Component, Reflect)]
+pub enum ProxyUiSpace {
+    /// world rects are converted through the active camera every time it moves
+    #[default]
+    World,
+    /// world rects are relative to the active camera and the proxied target is parented to it,
+    /// so it stays fixed on screen without a conversion every time the camera moves.
+    ///
+    /// the z of the applied transform is relative to the camera as well
+    CameraLocal,
+}
@@ -0,0 +1,18 @@
+use bevy::prelude::*;
+
+use crate::prelude::*;
+
+/// Fits a bevy_ecs_tilemap tilemap into its proxy node.
+///
+/// Add this to the proxied tilemap entity, the transform is computed from
+/// `TilemapSize`, `TilemapGridSize` and `TilemapType` whenever the `ProxyUiNodeState` changes
+#[derive(Debug, Default, Clone, Component, Reflect)]
+pub struct ProxyUiTilemap {
+    pub fit: ProxyUiFit,
+}
+
+impl ProxyUiTilemap {
+    pub fn new(fit: ProxyUiFit) -> Self {
+        Self { fit }
+    }
+}
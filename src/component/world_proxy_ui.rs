@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+
+/// Links an absolutely positioned ui node to a world entity, the reverse of `ProxyUi`.
+///
+/// The node follows the target's `GlobalTransform` through the active camera, centered on it,
+/// or covers the target's bounds when given. The node is expected to be a root node
+#[derive(Debug, Component, Reflect)]
+pub struct WorldProxyUi {
+    pub(crate) target_entity: Entity,
+    pub(crate) bounds: Option<Rect>,
+    pub(crate) offset: Vec2,
+}
+
+impl WorldProxyUi {
+    pub fn follow(target_entity: Entity) -> Self {
+        Self {
+            target_entity,
+            bounds: None,
+            offset: Vec2::ZERO,
+        }
+    }
+    /// sizes the node to cover `bounds`, a rect in the local space of the target
+    pub fn with_bounds(mut self, bounds: Rect) -> Self {
+        self.bounds = Some(bounds);
+        self
+    }
+    /// moves the node by `offset` ui pixels, y down
+    pub fn with_offset(mut self, offset: Vec2) -> Self {
+        self.offset = offset;
+        self
+    }
+    pub fn target_entity(&self) -> Entity {
+        self.target_entity
+    }
+}
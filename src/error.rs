@@ -1,41 +1,61 @@
 use std::fmt::Formatter;
 
-#[derive(Debug, PartialEq)]
+use bevy::prelude::Entity;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub(crate) enum ProxyUiStateError {
-    MultipleProxyUiPerEntity,
-    DuplicateProxyUi,
+    MultipleProxyUiPerEntity {
+        proxy_ui_entity: Entity,
+        target_entity: Entity,
+    },
+    DuplicateProxyUi {
+        proxy_ui_entity: Entity,
+        target_entity: Entity,
+    },
+    MissingTarget {
+        proxy_ui_entity: Entity,
+        target_entity: Entity,
+    },
 }
 
 impl std::fmt::Display for ProxyUiStateError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let str_value: &'static str = self.into();
-        write!(f, "{str_value}")
-    }
-}
-
-impl From<ProxyUiStateError> for &'static str {
-    fn from(error: ProxyUiStateError) -> Self {
-        error.into()
-    }
-}
-
-impl From<&ProxyUiStateError> for &'static str {
-    fn from(error: &ProxyUiStateError) -> Self {
-        match error {
-            ProxyUiStateError::MultipleProxyUiPerEntity => "Multiple proxy UI per entity",
-            ProxyUiStateError::DuplicateProxyUi => "Duplicate proxy UI",
+        match self {
+            ProxyUiStateError::MultipleProxyUiPerEntity {
+                proxy_ui_entity,
+                target_entity,
+            } => write!(
+                f,
+                "Multiple proxy UI per entity, {proxy_ui_entity:?} is already proxying another target than {target_entity:?}"
+            ),
+            ProxyUiStateError::DuplicateProxyUi {
+                proxy_ui_entity,
+                target_entity,
+            } => write!(
+                f,
+                "Duplicate proxy UI, {proxy_ui_entity:?} is already proxying {target_entity:?}"
+            ),
+            ProxyUiStateError::MissingTarget {
+                proxy_ui_entity,
+                target_entity,
+            } => write!(
+                f,
+                "Missing proxy target, {target_entity:?} for {proxy_ui_entity:?} does not exist"
+            ),
         }
     }
 }
 
+impl std::error::Error for ProxyUiStateError {}
+
 impl From<ProxyUiStateError> for String {
     fn from(error: ProxyUiStateError) -> Self {
-        error.into()
+        error.to_string()
     }
 }
 
 impl From<&ProxyUiStateError> for String {
     fn from(error: &ProxyUiStateError) -> Self {
-        error.into()
+        error.to_string()
     }
 }
@@ -0,0 +1,5 @@
+pub use offscreen_indicator_event::*;
+pub use proxy_ui_interaction_event::*;
+
+pub mod offscreen_indicator_event;
+pub mod proxy_ui_interaction_event;
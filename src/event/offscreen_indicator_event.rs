@@ -0,0 +1,10 @@
+use bevy::prelude::*;
+
+/// Sent when the target of an `OffscreenIndicator` moves on or off screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Event)]
+pub struct OffscreenIndicatorEvent {
+    pub proxy_ui_entity: Entity,
+    pub target_entity: Entity,
+    /// true when the target moved on screen, false when the indicator moved to the edge
+    pub on_screen: bool,
+}
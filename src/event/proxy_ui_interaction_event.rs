@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum ProxyUiInteractionKind {
+    Pressed,
+    Released,
+    Entered,
+    Left,
+}
+
+impl ProxyUiInteractionKind {
+    /// returns the events for a change from `previous` to `current`, in the order they happened
+    pub fn from_change(previous: Interaction, current: Interaction) -> Vec<Self> {
+        use Interaction::*;
+        match (previous, current) {
+            (None, Hovered) => vec![Self::Entered],
+            (None, Pressed) => vec![Self::Entered, Self::Pressed],
+            (Hovered, Pressed) => vec![Self::Pressed],
+            (Hovered, None) => vec![Self::Left],
+            (Pressed, Hovered) => vec![Self::Released],
+            (Pressed, None) => vec![Self::Released, Self::Left],
+            _ => vec![],
+        }
+    }
+}
+
+/// Sent when the `Interaction` of a proxy ui node changes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Event)]
+pub struct ProxyUiInteractionEvent {
+    pub proxy_ui_entity: Entity,
+    pub target_entity: Entity,
+    pub kind: ProxyUiInteractionKind,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_change() {
+        use ProxyUiInteractionKind::*;
+        assert_eq!(
+            ProxyUiInteractionKind::from_change(Interaction::None, Interaction::Pressed),
+            vec![Entered, Pressed]
+        );
+        assert_eq!(
+            ProxyUiInteractionKind::from_change(Interaction::Pressed, Interaction::None),
+            vec![Released, Left]
+        );
+        assert_eq!(
+            ProxyUiInteractionKind::from_change(Interaction::Hovered, Interaction::Hovered),
+            vec![]
+        );
+    }
+}
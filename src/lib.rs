@@ -1,8 +1,15 @@
+// lets the derive macros refer to `::bevy_ui_proxy` from inside this crate
+extern crate self as bevy_ui_proxy;
+
+mod apply;
+mod command;
 mod component;
 mod error;
+mod event;
 mod plugin;
 pub mod prelude;
 mod resource;
 mod system;
+mod system_param;
 pub(crate) mod test;
 pub(crate) mod util;
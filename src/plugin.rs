@@ -3,17 +3,155 @@ use bevy::prelude::*;
 use crate::prelude::*;
 use crate::resource::proxy_ui_entity_map::ProxyUiEntityMap;
 use crate::system::proxy_ui_added::proxy_ui_added;
+use crate::system::proxy_ui_camera::proxy_ui_camera;
+use crate::system::proxy_ui_clip::proxy_ui_clip;
+use crate::system::proxy_ui_interaction::proxy_ui_interaction;
+use crate::system::proxy_ui_mirror::proxy_ui_mirror;
+use crate::system::proxy_ui_render_texture::proxy_ui_render_texture;
+#[cfg(feature = "bevy_ecs_tilemap")]
+use crate::system::proxy_ui_tilemap::proxy_ui_tilemap;
 use crate::system::proxy_ui_update::proxy_ui_update;
+use crate::system::world_proxy_ui::{world_proxy_ui_added, world_proxy_ui_update};
 
-pub struct BevyUiProxyPlugin;
+/// system sets the plugin runs in `Update`, in order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SystemSet)]
+pub enum ProxyUiSet {
+    /// links newly added `ProxyUi` nodes to their targets
+    Link,
+    /// updates `ProxyUiNodeState` for every linked node
+    Update,
+    /// applies `ProxyUiNodeState` to the proxied targets
+    Apply,
+    /// hides and crops clipped proxied targets, see `ProxyUiClip`
+    Clip,
+}
+
+type ConfigureSets = Box<dyn Fn(&mut App) + Send + Sync>;
+
+#[derive(Default)]
+pub struct BevyUiProxyPlugin {
+    configure_sets: Vec<ConfigureSets>,
+}
+
+impl BevyUiProxyPlugin {
+    /// only runs the proxy systems while `state` is active,
+    /// linked nodes keep their last `ProxyUiNodeState` otherwise
+    pub fn run_in_state<S: States>(mut self, state: S) -> Self {
+        self.configure_sets.push(Box::new(move |app| {
+            for set in [
+                ProxyUiSet::Link,
+                ProxyUiSet::Update,
+                ProxyUiSet::Apply,
+                ProxyUiSet::Clip,
+            ] {
+                app.configure_sets(Update, set.run_if(in_state(state.clone())));
+            }
+        }));
+        self
+    }
+}
 
 impl Plugin for BevyUiProxyPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<ProxyUiEntityMap>()
+            .init_resource::<ProxyUiErrorPolicy>()
+            .add_event::<ProxyUiInteractionEvent>()
+            .add_event::<OffscreenIndicatorEvent>()
+            .register_type::<ProxyUiErrorPolicy>()
+            .register_type::<ProxyUiDeclutter>()
+            .register_type::<OffscreenIndicator>()
+            .register_type::<ProxyUiZRange>()
             .register_type::<ProxyUiProxied>()
             .register_type::<ProxyUiTarget>()
             .register_type::<ProxyUiNodeState>()
             .register_type::<ProxyUi>()
-            .add_systems(Update, (proxy_ui_added, proxy_ui_update).chain());
+            .register_type::<ProxyUiAnchor>()
+            .register_type::<ProxyUiBox>()
+            .register_type::<ProxyUiCameraFraming>()
+            .register_type::<ProxyUiClip>()
+            .register_type::<ProxyUiFit>()
+            .register_type::<ProxyUiInteraction>()
+            .register_type::<ProxyUiMirror>()
+            .register_type::<ProxyUiPaused>()
+            .register_type::<ProxyUiPixelSnap>()
+            .register_type::<ProxyUiPriority>()
+            .register_type::<ProxyUiRenderTexture>()
+            .register_type::<ProxyUiSpace>()
+            .register_type::<WorldProxyUi>()
+            .configure_sets(
+                Update,
+                (
+                    ProxyUiSet::Link,
+                    ProxyUiSet::Update,
+                    ProxyUiSet::Apply,
+                    ProxyUiSet::Clip,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                Update,
+                (
+                    proxy_ui_added.in_set(ProxyUiSet::Link),
+                    world_proxy_ui_added.in_set(ProxyUiSet::Link),
+                    proxy_ui_update.in_set(ProxyUiSet::Update),
+                    world_proxy_ui_update.in_set(ProxyUiSet::Update),
+                    proxy_ui_mirror.in_set(ProxyUiSet::Apply),
+                    proxy_ui_camera.in_set(ProxyUiSet::Apply),
+                    proxy_ui_render_texture
+                        .run_if(resource_exists::<Assets<Image>>)
+                        .in_set(ProxyUiSet::Apply),
+                    proxy_ui_interaction.in_set(ProxyUiSet::Apply),
+                    proxy_ui_clip.in_set(ProxyUiSet::Clip),
+                ),
+            );
+
+        for configure_sets in self.configure_sets.iter() {
+            configure_sets(app);
+        }
+
+        #[cfg(feature = "bevy_ecs_tilemap")]
+        app.register_type::<ProxyUiTilemap>()
+            .add_systems(Update, proxy_ui_tilemap.in_set(ProxyUiSet::Apply));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    use crate::test::lib::*;
+
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, States)]
+    enum TestState {
+        #[default]
+        Closed,
+        Open,
+    }
+
+    #[test]
+    fn test_run_in_state() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_state::<TestState>()
+            .add_plugins(BevyUiProxyPlugin::default().run_in_state(TestState::Open));
+        let proxied_target_entity = app.world.spawn(DummyComponent).id();
+        app.world.spawn(ProxyUi::proxy(proxied_target_entity));
+
+        app.update();
+        assert!(!app
+            .world
+            .entity(proxied_target_entity)
+            .contains::<ProxyUiProxied>());
+
+        app.world
+            .resource_mut::<NextState<TestState>>()
+            .set(TestState::Open);
+        app.update();
+        assert!(app
+            .world
+            .entity(proxied_target_entity)
+            .contains::<ProxyUiProxied>());
     }
 }
@@ -1,3 +1,12 @@
+pub use crate::apply::*;
+#[cfg(feature = "derive")]
+pub use bevy_ui_proxy_derive::ProxyUiApply;
+pub use crate::command::*;
 pub use crate::component::*;
-pub use crate::plugin::BevyUiProxyPlugin;
+pub use crate::event::*;
+pub use crate::plugin::{BevyUiProxyPlugin, ProxyUiSet};
+pub use crate::resource::proxy_ui_declutter::ProxyUiDeclutter;
+pub use crate::resource::proxy_ui_error_policy::ProxyUiErrorPolicy;
+pub use crate::resource::proxy_ui_z_range::ProxyUiZRange;
+pub use crate::system_param::*;
 
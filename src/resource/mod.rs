@@ -1 +1,4 @@
+pub mod proxy_ui_declutter;
 pub(crate) mod proxy_ui_entity_map;
+pub mod proxy_ui_error_policy;
+pub mod proxy_ui_z_range;
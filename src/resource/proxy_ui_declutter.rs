@@ -0,0 +1,132 @@
+use bevy::prelude::*;
+
+/// Pushes overlapping `WorldProxyUi` nodes apart, not inserted by default.
+///
+/// Labels are moved from where they are anchored every frame, so the result only changes when
+/// the labels move. Lower `ProxyUiPriority` labels move first, ties are broken by entity
+#[derive(Debug, Clone, Copy, PartialEq, Resource, Reflect)]
+#[reflect(Resource)]
+pub struct ProxyUiDeclutter {
+    /// the furthest a label is moved from its anchored position, in ui pixels
+    pub max_displacement: f32,
+    /// extra space kept between labels, in ui pixels
+    pub padding: f32,
+    /// the number of separation passes
+    pub iterations: u32,
+    /// hides labels still overlapping a label with a higher priority
+    pub hide_low_priority: bool,
+}
+
+impl Default for ProxyUiDeclutter {
+    fn default() -> Self {
+        Self {
+            max_displacement: 50.0,
+            padding: 2.0,
+            iterations: 4,
+            hide_low_priority: false,
+        }
+    }
+}
+
+impl ProxyUiDeclutter {
+    /// returns the offset and whether the label should be hidden for each label,
+    /// `labels` are the anchored ui rects and priorities, sorted from highest priority
+    pub fn resolve(&self, labels: &[(Rect, i32)]) -> Vec<(Vec2, bool)> {
+        let padded = |rect: Rect, offset: Vec2| Rect {
+            min: rect.min + offset - Vec2::splat(self.padding / 2.0),
+            max: rect.max + offset + Vec2::splat(self.padding / 2.0),
+        };
+        let mut offsets = vec![Vec2::ZERO; labels.len()];
+        for _ in 0..self.iterations {
+            for i in 0..labels.len() {
+                for j in i + 1..labels.len() {
+                    let (rect_i, priority_i) = labels[i];
+                    let (rect_j, priority_j) = labels[j];
+                    let a = padded(rect_i, offsets[i]);
+                    let b = padded(rect_j, offsets[j]);
+                    let overlap = a.intersect(b);
+                    if overlap.is_empty() {
+                        continue;
+                    }
+                    // push apart along the axis with the least overlap
+                    let delta = b.center() - a.center();
+                    let push = if overlap.width() < overlap.height() {
+                        Vec2::new(overlap.width() * sign(delta.x), 0.0)
+                    } else {
+                        Vec2::new(0.0, overlap.height() * sign(delta.y))
+                    };
+                    if priority_i == priority_j {
+                        offsets[i] = self.clamp(offsets[i] - push / 2.0);
+                        offsets[j] = self.clamp(offsets[j] + push / 2.0);
+                    } else {
+                        offsets[j] = self.clamp(offsets[j] + push);
+                    }
+                }
+            }
+        }
+
+        let mut hidden = vec![false; labels.len()];
+        if self.hide_low_priority {
+            for j in 0..labels.len() {
+                let (rect_j, priority_j) = labels[j];
+                hidden[j] = (0..j).any(|i| {
+                    let (rect_i, priority_i) = labels[i];
+                    !hidden[i]
+                        && priority_i > priority_j
+                        && !rect_i
+                            .intersect(Rect {
+                                min: rect_j.min + offsets[j] - offsets[i],
+                                max: rect_j.max + offsets[j] - offsets[i],
+                            })
+                            .is_empty()
+                });
+            }
+        }
+        offsets.into_iter().zip(hidden).collect()
+    }
+    fn clamp(&self, offset: Vec2) -> Vec2 {
+        offset.clamp_length_max(self.max_displacement)
+    }
+}
+
+/// like `f32::signum` but never 0, so labels at the same position still separate
+fn sign(value: f32) -> f32 {
+    if value < 0.0 {
+        -1.0
+    } else {
+        1.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve() {
+        let declutter = ProxyUiDeclutter {
+            padding: 0.0,
+            ..default()
+        };
+        let labels = [
+            (Rect::new(0.0, 0.0, 40.0, 10.0), 1),
+            (Rect::new(0.0, 5.0, 40.0, 15.0), 0),
+            (Rect::new(100.0, 0.0, 140.0, 10.0), 0),
+        ];
+        let resolved = declutter.resolve(&labels);
+        // the lower priority label moves down out of the way, the others stay
+        assert_eq!(resolved[0], (Vec2::ZERO, false));
+        assert_eq!(resolved[1], (Vec2::new(0.0, 5.0), false));
+        assert_eq!(resolved[2], (Vec2::ZERO, false));
+        // the same input resolves the same way
+        assert_eq!(declutter.resolve(&labels), resolved);
+
+        let declutter = ProxyUiDeclutter {
+            max_displacement: 1.0,
+            hide_low_priority: true,
+            ..declutter
+        };
+        let resolved = declutter.resolve(&labels);
+        assert_eq!(resolved[1], (Vec2::new(0.0, 1.0), true));
+    }
+}
@@ -28,7 +28,11 @@ impl ProxyUiEntityMap {
         &mut self,
         proxy_ui_entity: &Entity,
     ) -> Option<(Entity, Entity)> {
-        self.0.remove_by_left(proxy_ui_entity)
+        self.0.remove_by_right(proxy_ui_entity)
+    }
+    /// iterates over `(proxied_target_entity, proxy_ui_entity)`
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (Entity, Entity)> + '_ {
+        self.0.iter().map(|(left, right)| (*left, *right))
     }
     pub(crate) fn insert(
         &mut self,
@@ -55,5 +59,9 @@ mod tests {
         assert_eq!(proxy_ui_entity_map.get_proxy_entity(&proxy_target_entity), Some(&proxy_ui_entity));
         assert_eq!(proxy_ui_entity_map.remove_by_proxied_target_entity(&proxy_target_entity), Some((proxy_target_entity, proxy_ui_entity)));
         assert_eq!(proxy_ui_entity_map.remove_by_proxy_entity(&proxy_ui_entity), None);
+        assert_eq!(proxy_ui_entity_map.insert(proxy_target_entity, proxy_ui_entity), Overwritten::Neither);
+        assert_eq!(proxy_ui_entity_map.remove_by_proxy_entity(&proxy_ui_entity), Some((proxy_target_entity, proxy_ui_entity)));
+        assert!(!proxy_ui_entity_map.is_proxied(&proxy_target_entity));
+        assert_eq!(proxy_ui_entity_map.remove_by_proxied_target_entity(&proxy_target_entity), None);
     }
 }
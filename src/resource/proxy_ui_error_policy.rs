@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+
+/// How a `ProxyUi` with a target entity that doesn't exist (yet) is handled.
+///
+/// Can be overridden per link with `ProxyUi::with_error_policy`.
+///
+/// The policy only covers the initial link. Once linked, a target that is despawned is unlinked
+/// right away regardless of the policy, a despawned entity can't appear again so there is
+/// nothing to wait for. Spawn a new `ProxyUi` to link a replacement target
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Resource, Reflect)]
+#[reflect(Resource)]
+pub enum ProxyUiErrorPolicy {
+    /// logs a warning and drops the `ProxyUi`
+    #[default]
+    Reject,
+    /// tries again on the next frames, up to the given number of retries, before rejecting
+    Retry(u32),
+    /// keeps the `ProxyUi` around until the target entity appears
+    Wait,
+}
@@ -0,0 +1,48 @@
+use bevy::prelude::*;
+
+/// Maps the stacking order of proxy ui nodes in `UiStack` to a world z range,
+/// so overlapping proxied targets stack like their nodes do.
+///
+/// Not inserted by default, the z of proxied targets is left alone without it
+#[derive(Debug, Clone, Copy, PartialEq, Resource, Reflect)]
+#[reflect(Resource)]
+pub struct ProxyUiZRange {
+    /// the z of the bottom most node
+    pub min: f32,
+    /// the z of the top most node
+    pub max: f32,
+}
+
+impl Default for ProxyUiZRange {
+    fn default() -> Self {
+        Self { min: 0.0, max: 1.0 }
+    }
+}
+
+impl ProxyUiZRange {
+    pub fn new(min: f32, max: f32) -> Self {
+        Self { min, max }
+    }
+    /// returns the z for the node at `stack_index` out of `stack_len` nodes
+    pub fn z(&self, stack_index: u32, stack_len: usize) -> f32 {
+        if stack_len <= 1 {
+            return self.min;
+        }
+        let t = stack_index as f32 / (stack_len - 1) as f32;
+        self.min + (self.max - self.min) * t.clamp(0.0, 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_z() {
+        let z_range = ProxyUiZRange::new(10.0, 20.0);
+        assert_eq!(z_range.z(0, 0), 10.0);
+        assert_eq!(z_range.z(0, 5), 10.0);
+        assert_eq!(z_range.z(2, 5), 15.0);
+        assert_eq!(z_range.z(4, 5), 20.0);
+    }
+}
@@ -1,2 +1,11 @@
 pub(crate) mod proxy_ui_added;
+pub(crate) mod proxy_ui_apply;
+pub(crate) mod proxy_ui_camera;
+pub(crate) mod proxy_ui_clip;
+pub(crate) mod proxy_ui_interaction;
+pub(crate) mod proxy_ui_mirror;
+pub(crate) mod proxy_ui_render_texture;
+#[cfg(feature = "bevy_ecs_tilemap")]
+pub(crate) mod proxy_ui_tilemap;
 pub(crate) mod proxy_ui_update;
+pub(crate) mod world_proxy_ui;
@@ -5,31 +5,103 @@ use crate::error::ProxyUiStateError;
 use crate::prelude::*;
 use crate::resource::proxy_ui_entity_map::ProxyUiEntityMap;
 
+#[allow(clippy::type_complexity)]
 #[derive(SystemParam)]
 pub(crate) struct ProxyUiAddedQueries<'w, 's> {
-    proxy_ui_added_query:
-        Query<'w, 's, (Entity, &'static ProxyUi, Option<&'static Node>), Added<ProxyUi>>,
+    proxy_ui_added_query: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static ProxyUi,
+            Option<&'static Node>,
+            Option<&'static ProxyUiPending>,
+            Option<&'static ProxyUiMirror>,
+        ),
+        Or<(Added<ProxyUi>, With<ProxyUiPending>)>,
+    >,
     proxy_target_node_check_query: Query<'w, 's, Option<&'static Node>>,
 }
 
 pub(crate) fn proxy_ui_added(
     mut commands: Commands,
     mut proxy_ui_entity_map: ResMut<ProxyUiEntityMap>,
+    error_policy: Res<ProxyUiErrorPolicy>,
     queries: ProxyUiAddedQueries,
 ) {
-    for (proxy_ui_entity, proxy_ui, option_node) in queries.proxy_ui_added_query.iter() {
-        commands.entity(proxy_ui_entity).remove::<ProxyUi>();
-
-        if let Err(err) = validate_state(&proxy_ui_entity_map, &proxy_ui_entity, proxy_ui) {
+    for (proxy_ui_entity, proxy_ui, option_node, option_pending, option_mirror) in
+        queries.proxy_ui_added_query.iter()
+    {
+        if let Err(err) = validate_state(
+            &proxy_ui_entity_map,
+            &proxy_ui_entity,
+            proxy_ui.target_entity,
+        ) {
             warn!("{err}");
+            commands
+                .entity(proxy_ui_entity)
+                .remove::<(ProxyUi, ProxyUiPending)>();
+            continue;
+        }
+
+        let proxy_target_entity = proxy_ui.target_entity;
+        let Ok(option_bad_node) = queries
+            .proxy_target_node_check_query
+            .get(proxy_target_entity)
+        else {
+            let err = ProxyUiStateError::MissingTarget {
+                proxy_ui_entity,
+                target_entity: proxy_target_entity,
+            };
+            let attempts = option_pending.map(|pending| pending.attempts).unwrap_or(0) + 1;
+            match proxy_ui.error_policy.unwrap_or(*error_policy) {
+                ProxyUiErrorPolicy::Retry(max_retries) if attempts <= max_retries => {
+                    debug!("{err}, retry {attempts}/{max_retries}");
+                    commands
+                        .entity(proxy_ui_entity)
+                        .insert(ProxyUiPending { attempts });
+                }
+                ProxyUiErrorPolicy::Wait => {
+                    if option_pending.is_none() {
+                        debug!("{err}, waiting for it to appear");
+                        commands
+                            .entity(proxy_ui_entity)
+                            .insert(ProxyUiPending { attempts });
+                    }
+                }
+                _ => {
+                    warn!("{err}, removing proxy");
+                    commands
+                        .entity(proxy_ui_entity)
+                        .remove::<(ProxyUi, ProxyUiPending)>();
+                }
+            }
+            continue;
+        };
+
+        commands
+            .entity(proxy_ui_entity)
+            .remove::<(ProxyUi, ProxyUiPending)>();
+
+        if proxy_ui.mirror {
+            if option_bad_node.is_none() {
+                warn!("mirrored entities must be ui nodes, entity: {proxy_target_entity:?}, removing proxy.");
+                continue;
+            }
+            if option_mirror.is_none() {
+                commands
+                    .entity(proxy_ui_entity)
+                    .insert(ProxyUiMirror::default());
+            }
+        } else if option_bad_node.is_some() {
+            warn!("proxied entities can not contain ui nodes, entity: {proxy_target_entity:?}, removing proxy.");
             continue;
         }
 
         debug!("associating proxy target for {proxy_ui_entity:?}... ");
-        let proxy_target_entity = commands
-            .entity(proxy_ui.target_entity)
-            .insert(ProxyUiProxied(proxy_ui_entity))
-            .id();
+        commands
+            .entity(proxy_target_entity)
+            .insert(ProxyUiProxied(proxy_ui_entity));
         commands
             .entity(proxy_ui_entity)
             .insert(ProxyUiTarget(proxy_target_entity));
@@ -42,39 +114,34 @@ pub(crate) fn proxy_ui_added(
             });
         }
 
-        let option_bad_node = queries
-            .proxy_target_node_check_query
-            .get(proxy_target_entity)
-            .unwrap_or_else(|err| {
-                panic!("failed to find proxy: {proxy_target_entity:?} - {err:?}")
-            });
-        if option_bad_node.is_some() {
-            warn!("proxied entities can not contain ui nodes, entity: {proxy_target_entity:?}, removing proxy.");
-            commands.entity(proxy_ui_entity).remove::<ProxyUi>();
-            commands.entity(proxy_ui_entity).remove::<ProxyUiTarget>();
+        if let Some(focus_policy) = proxy_ui.focus_policy {
             commands
-                .entity(proxy_target_entity)
-                .remove::<ProxyUiProxied>();
-            continue;
+                .entity(proxy_ui_entity)
+                .insert((Interaction::default(), focus_policy));
         }
 
         proxy_ui_entity_map.insert(proxy_target_entity, proxy_ui_entity);
     }
 }
 
-fn validate_state(
+pub(crate) fn validate_state(
     proxy_ui_entity_map: &ProxyUiEntityMap,
     proxy_ui_entity: &Entity,
-    proxy_ui: &ProxyUi,
+    proxy_target_entity: Entity,
 ) -> Result<(), ProxyUiStateError> {
-    let proxy_target_entity = proxy_ui.target_entity;
     if proxy_ui_entity_map.contains_proxy(proxy_ui_entity) {
         if Some(&proxy_target_entity)
             != proxy_ui_entity_map.get_proxied_target_entity(proxy_ui_entity)
         {
-            Err(ProxyUiStateError::MultipleProxyUiPerEntity)
+            Err(ProxyUiStateError::MultipleProxyUiPerEntity {
+                proxy_ui_entity: *proxy_ui_entity,
+                target_entity: proxy_target_entity,
+            })
         } else {
-            Err(ProxyUiStateError::DuplicateProxyUi)
+            Err(ProxyUiStateError::DuplicateProxyUi {
+                proxy_ui_entity: *proxy_ui_entity,
+                target_entity: proxy_target_entity,
+            })
         }
     } else {
         Ok(())
@@ -120,4 +187,61 @@ mod tests {
             assert_eq!(proxy_target_entity, proxied.get_proxy_ui_entity());
         }
     }
+
+    fn count_proxy_ui(app: &mut App) -> usize {
+        app.world
+            .query_filtered::<Entity, With<ProxyUi>>()
+            .iter(&app.world)
+            .len()
+    }
+
+    #[test]
+    fn test_proxy_ui_added_missing_target_reject() {
+        let mut app = setup_test_app();
+        let missing_target_entity = app.world.spawn_empty().id();
+        app.world.despawn(missing_target_entity);
+        app.world.spawn(ProxyUi::proxy(missing_target_entity));
+        app.world.run_system_once(proxy_ui_added);
+        assert_eq!(count_proxy_ui(&mut app), 0);
+        assert_eq!(app.world.resource::<ProxyUiEntityMap>().iter().count(), 0);
+    }
+
+    #[test]
+    fn test_proxy_ui_added_missing_target_retry() {
+        let mut app = setup_test_app();
+        let missing_target_entity = app.world.spawn_empty().id();
+        app.world.despawn(missing_target_entity);
+        app.world.spawn(
+            ProxyUi::proxy(missing_target_entity).with_error_policy(ProxyUiErrorPolicy::Retry(2)),
+        );
+        app.world.run_system_once(proxy_ui_added);
+        app.world.run_system_once(proxy_ui_added);
+        assert_eq!(count_proxy_ui(&mut app), 1);
+        app.world.run_system_once(proxy_ui_added);
+        assert_eq!(count_proxy_ui(&mut app), 0);
+    }
+
+    #[test]
+    fn test_proxy_ui_added_missing_target_wait() {
+        let mut app = setup_test_app();
+        app.insert_resource(ProxyUiErrorPolicy::Wait);
+        // an entity that will only be spawned later on
+        let target_entity = Entity::from_raw(1000);
+        let proxy_ui_entity = app.world.spawn(ProxyUi::proxy(target_entity)).id();
+        for _ in 0..10 {
+            app.world.run_system_once(proxy_ui_added);
+        }
+        assert_eq!(count_proxy_ui(&mut app), 1);
+
+        app.world.get_or_spawn(target_entity);
+        app.world.run_system_once(proxy_ui_added);
+        assert_eq!(count_proxy_ui(&mut app), 0);
+        assert_eq!(
+            app.world
+                .resource::<ProxyUiEntityMap>()
+                .get_proxied_target_entity(&proxy_ui_entity),
+            Some(&target_entity)
+        );
+        assert!(app.world.entity(target_entity).contains::<ProxyUiProxied>());
+    }
 }
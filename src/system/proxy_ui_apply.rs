@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+
+use crate::prelude::*;
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn proxy_ui_apply<T: ProxyUiApply>(
+    mut query: Query<
+        (&ProxyUiNodeState, &mut T),
+        (
+            With<ProxyUiProxied>,
+            Or<(Changed<ProxyUiNodeState>, Added<T>)>,
+        ),
+    >,
+) {
+    for (node_state, mut applicator) in query.iter_mut() {
+        applicator.apply(node_state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use crate::system::proxy_ui_added::proxy_ui_added;
+    use crate::system::proxy_ui_update::proxy_ui_update;
+    use crate::test::lib::*;
+
+    use super::*;
+
+    #[derive(Debug, Default, Component)]
+    struct LightRadius(f32);
+
+    impl ProxyUiApply for LightRadius {
+        fn apply(&mut self, state: &ProxyUiNodeState) {
+            self.0 = state.get_world_rect().width() / 2.0;
+        }
+    }
+
+    #[test]
+    fn test_proxy_ui_apply() {
+        let mut app = setup_test_app();
+        let proxied_target_entity = app.world.spawn(LightRadius(-1.0)).id();
+        app.world.spawn(ProxyUi::proxy(proxied_target_entity));
+        app.world.run_system_once(proxy_ui_added);
+        app.world.run_system_once(proxy_ui_update);
+        app.world.run_system_once(proxy_ui_apply::<LightRadius>);
+
+        let node_state = app
+            .world
+            .get::<ProxyUiNodeState>(proxied_target_entity)
+            .unwrap();
+        let light_radius = app.world.get::<LightRadius>(proxied_target_entity).unwrap();
+        assert_eq!(light_radius.0, node_state.get_world_rect().width() / 2.0);
+    }
+}
@@ -0,0 +1,198 @@
+use bevy::prelude::*;
+use bevy::render::camera::Viewport;
+use bevy::window::PrimaryWindow;
+
+use crate::prelude::*;
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn proxy_ui_camera(
+    mut commands: Commands,
+    mut proxy_target_query: Query<
+        (
+            Entity,
+            &ProxyUiNodeState,
+            &mut Camera,
+            Option<&ProxyUiCameraFraming>,
+            Option<&mut OrthographicProjection>,
+            Option<&mut Transform>,
+            Has<ProxyUiCameraOffscreen>,
+        ),
+        (
+            With<ProxyUiProxied>,
+            Or<(Changed<ProxyUiNodeState>, Changed<ProxyUiCameraFraming>)>,
+        ),
+    >,
+    primary_window_query: Query<&Window, With<PrimaryWindow>>,
+) {
+    for (
+        camera_entity,
+        node_state,
+        mut camera,
+        option_framing,
+        option_projection,
+        option_transform,
+        is_offscreen,
+    ) in proxy_target_query.iter_mut()
+    {
+        // the target size is only computed once the camera has been rendered,
+        // node rects are relative to the primary window until then
+        let target_size = camera.physical_target_size().or_else(|| {
+            primary_window_query
+                .get_single()
+                .ok()
+                .map(|window| UVec2::new(window.physical_width(), window.physical_height()))
+        });
+        let option_viewport = viewport(
+            *node_state.get_physical_rect(),
+            target_size,
+            camera.viewport.clone().unwrap_or_default(),
+        );
+        match option_viewport {
+            Some(viewport) => {
+                camera.viewport = Some(viewport);
+                if is_offscreen {
+                    camera.is_active = true;
+                    commands
+                        .entity(camera_entity)
+                        .remove::<ProxyUiCameraOffscreen>();
+                }
+            }
+            // nothing to render into, keep the last viewport but stop rendering
+            None => {
+                if camera.is_active {
+                    camera.is_active = false;
+                    commands
+                        .entity(camera_entity)
+                        .insert(ProxyUiCameraOffscreen);
+                }
+            }
+        }
+
+        let Some(framing) = option_framing else {
+            continue;
+        };
+        if let Some(mut transform) = option_transform {
+            let z = transform.translation.z;
+            transform.translation = framing.region.center().extend(z);
+        }
+        if let Some(mut projection) = option_projection {
+            if let Some(scale) = framing.scale(&projection, node_state.get_logical_rect().size()) {
+                projection.scale = scale;
+            }
+        }
+    }
+}
+
+/// returns the viewport covering the part of `physical_rect` inside the render target,
+/// viewports outside of the target are rejected by wgpu so `None` is returned when nothing
+/// overlaps
+fn viewport(
+    physical_rect: Rect,
+    target_size: Option<UVec2>,
+    viewport: Viewport,
+) -> Option<Viewport> {
+    let physical_rect = match target_size {
+        Some(target_size) => {
+            physical_rect.intersect(Rect::from_corners(Vec2::ZERO, target_size.as_vec2()))
+        }
+        None => physical_rect,
+    };
+    let min = physical_rect.min.max(Vec2::ZERO).round().as_uvec2();
+    let max = physical_rect.max.max(Vec2::ZERO).round().as_uvec2();
+    // zero sized viewports are not allowed either
+    let physical_size = max.saturating_sub(min);
+    if physical_size.cmpeq(UVec2::ZERO).any() {
+        return None;
+    }
+    Some(Viewport {
+        physical_position: min,
+        physical_size,
+        ..viewport
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use crate::system::proxy_ui_added::proxy_ui_added;
+    use crate::system::proxy_ui_update::proxy_ui_update;
+    use crate::test::lib::*;
+
+    use super::*;
+
+    #[test]
+    fn test_proxy_ui_camera() {
+        let mut app = setup_test_app();
+        app.world.spawn((Window::default(), PrimaryWindow));
+        let camera_entity = app
+            .world
+            .spawn((
+                Camera2dBundle::default(),
+                ProxyUiCameraFraming::new(Rect::new(0.0, 0.0, 100.0, 50.0)),
+            ))
+            .id();
+        let proxy_ui_entity = app
+            .world
+            .spawn(ProxyUi::proxy(camera_entity))
+            .insert(NodeBundle {
+                node: sized_node(Vec2::new(200.0, 100.0)),
+                global_transform: GlobalTransform::from_xyz(200.0, 100.0, 0.0),
+                ..default()
+            })
+            .id();
+        app.world.run_system_once(proxy_ui_added);
+        app.world.run_system_once(proxy_ui_update);
+        app.world.run_system_once(proxy_ui_camera);
+
+        let camera = app.world.get::<Camera>(camera_entity).unwrap();
+        let viewport = camera.viewport.as_ref().unwrap();
+        assert_eq!(viewport.physical_position, UVec2::new(100, 50));
+        assert_eq!(viewport.physical_size, UVec2::new(200, 100));
+        let transform = app.world.get::<Transform>(camera_entity).unwrap();
+        assert_eq!(transform.translation.truncate(), Vec2::new(50.0, 25.0));
+        // the 100x50 region fills the 200x100 node
+        let projection = app
+            .world
+            .get::<OrthographicProjection>(camera_entity)
+            .unwrap();
+        assert_eq!(projection.scale, 0.5);
+
+        // the camera stops rendering while the node is outside of the window
+        let move_node = |app: &mut App, position: Vec3| {
+            app.world
+                .entity_mut(proxy_ui_entity)
+                .insert(GlobalTransform::from_translation(position));
+            app.world.run_system_once(proxy_ui_update);
+            app.world.run_system_once(proxy_ui_camera);
+            app.world.get::<Camera>(camera_entity).unwrap().clone()
+        };
+        let camera = move_node(&mut app, Vec3::new(3000.0, 100.0, 0.0));
+        assert!(!camera.is_active);
+        let camera = move_node(&mut app, Vec3::new(300.0, 100.0, 0.0));
+        assert!(camera.is_active);
+        assert_eq!(
+            camera.viewport.unwrap().physical_position,
+            UVec2::new(200, 50)
+        );
+    }
+
+    #[test]
+    fn test_viewport() {
+        let target_size = Some(UVec2::new(1280, 720));
+        let partially_outside = viewport(
+            Rect::new(-50.0, 600.0, 150.0, 800.0),
+            target_size,
+            Viewport::default(),
+        )
+        .unwrap();
+        assert_eq!(partially_outside.physical_position, UVec2::new(0, 600));
+        assert_eq!(partially_outside.physical_size, UVec2::new(150, 120));
+        let fully_outside = viewport(
+            Rect::new(2000.0, 1000.0, 2100.0, 1100.0),
+            target_size,
+            Viewport::default(),
+        );
+        assert!(fully_outside.is_none());
+    }
+}
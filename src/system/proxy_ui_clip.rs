@@ -0,0 +1,209 @@
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+
+use crate::prelude::*;
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn proxy_ui_clip(
+    mut commands: Commands,
+    images: Option<Res<Assets<Image>>>,
+    mut query: Query<
+        (
+            Entity,
+            &ProxyUiNodeState,
+            Option<&Visibility>,
+            Option<&ProxyUiClipped>,
+            Option<(&mut Sprite, &Handle<Image>)>,
+        ),
+        (With<ProxyUiProxied>, Changed<ProxyUiNodeState>),
+    >,
+) {
+    for (entity, node_state, option_visibility, option_clipped, option_sprite) in query.iter_mut() {
+        let mut clipped = ProxyUiClipped::default();
+        let previous = option_clipped.map(|clipped| (clipped.hidden, clipped.sprite));
+        let (hidden_visibility, original_sprite) = previous.unwrap_or_default();
+
+        let visible_rect = match node_state.get_clip() {
+            ProxyUiClip::Ignore => Some(*node_state.get_world_rect()),
+            ProxyUiClip::Hide | ProxyUiClip::Crop => node_state.get_visible_world_rect(),
+        };
+
+        match visible_rect {
+            None => {
+                // keep the visibility from before the target was first hidden
+                clipped.hidden = hidden_visibility
+                    .or_else(|| option_visibility.copied())
+                    .or(Some(Visibility::Inherited));
+                commands.entity(entity).insert(Visibility::Hidden);
+            }
+            Some(_) => {
+                if let Some(visibility) = hidden_visibility {
+                    commands.entity(entity).insert(visibility);
+                }
+            }
+        }
+
+        if let Some((mut sprite, image_handle)) = option_sprite {
+            let world_rect = *node_state.get_world_rect();
+            let crop_rect = visible_rect
+                .filter(|_| node_state.get_clip() == &ProxyUiClip::Crop)
+                .filter(|visible_rect| *visible_rect != world_rect);
+            let original =
+                original_sprite.unwrap_or((sprite.rect, sprite.custom_size, sprite.anchor));
+            match crop_rect {
+                Some(crop_rect) => {
+                    let image_size = images
+                        .as_ref()
+                        .and_then(|images| images.get(image_handle))
+                        .map(|image| image.size_f32());
+                    let (original_rect, _, original_anchor) = &original;
+                    if let Some(texture_rect) = original_rect.or_else(|| {
+                        image_size.map(|image_size| Rect::from_corners(Vec2::ZERO, image_size))
+                    }) {
+                        sprite.rect = Some(crop_texture_rect(texture_rect, world_rect, crop_rect));
+                        sprite.custom_size = Some(crop_rect.size());
+                        // keep the visible part in place, the transform is at the center of the
+                        // anchor of the uncropped sprite
+                        let pivot = world_rect.min
+                            + (original_anchor.as_vec() + Vec2::splat(0.5)) * world_rect.size();
+                        sprite.anchor = Anchor::Custom(
+                            (pivot - crop_rect.min) / crop_rect.size() - Vec2::splat(0.5),
+                        );
+                        clipped.sprite = Some(original);
+                    }
+                }
+                None => {
+                    if let Some((rect, custom_size, anchor)) = original_sprite {
+                        sprite.rect = rect;
+                        sprite.custom_size = custom_size;
+                        sprite.anchor = anchor;
+                    }
+                }
+            }
+        }
+
+        if clipped.hidden.is_some() || clipped.sprite.is_some() {
+            commands.entity(entity).insert(clipped);
+        } else if option_clipped.is_some() {
+            commands.entity(entity).remove::<ProxyUiClipped>();
+        }
+    }
+}
+
+/// returns the part of `texture_rect` covering `crop_rect` when the texture covers `world_rect`,
+/// textures are y down while world space is y up
+fn crop_texture_rect(texture_rect: Rect, world_rect: Rect, crop_rect: Rect) -> Rect {
+    let min = (crop_rect.min - world_rect.min) / world_rect.size();
+    let max = (crop_rect.max - world_rect.min) / world_rect.size();
+    let size = texture_rect.size();
+    Rect {
+        min: texture_rect.min + Vec2::new(min.x, 1.0 - max.y) * size,
+        max: texture_rect.min + Vec2::new(max.x, 1.0 - min.y) * size,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use crate::system::proxy_ui_added::proxy_ui_added;
+    use crate::system::proxy_ui_update::proxy_ui_update;
+    use crate::test::lib::*;
+
+    use super::*;
+
+    fn run_clip(app: &mut App, clip: Option<Rect>) {
+        let mut query = app.world.query_filtered::<Entity, With<ProxyUiTarget>>();
+        let proxy_ui_entity = query.single(&app.world);
+        match clip {
+            Some(clip) => app
+                .world
+                .entity_mut(proxy_ui_entity)
+                .insert(CalculatedClip { clip }),
+            None => app
+                .world
+                .entity_mut(proxy_ui_entity)
+                .remove::<CalculatedClip>(),
+        };
+        app.world.run_system_once(proxy_ui_update);
+        app.world.run_system_once(proxy_ui_clip);
+    }
+
+    #[test]
+    fn test_proxy_ui_clip() {
+        let mut app = setup_test_app();
+        spawn_window_camera(&mut app);
+        let sprite = Sprite {
+            rect: Some(Rect::new(0.0, 0.0, 16.0, 16.0)),
+            ..default()
+        };
+        let target_entity = app
+            .world
+            .spawn((
+                sprite.clone(),
+                Handle::<Image>::default(),
+                Visibility::Visible,
+            ))
+            .id();
+        // the node covers the world rect (-50, -50) (50, 50)
+        app.world.spawn((
+            ProxyUi::proxy(target_entity),
+            ProxyUiClip::Crop,
+            NodeBundle {
+                node: sized_node(Vec2::splat(100.0)),
+                global_transform: GlobalTransform::from_xyz(640.0, 360.0, 0.0),
+                ..default()
+            },
+        ));
+        app.world.run_system_once(proxy_ui_added);
+
+        // only the left half of the node is visible
+        run_clip(&mut app, Some(Rect::new(590.0, 310.0, 640.0, 410.0)));
+        let cropped = app.world.get::<Sprite>(target_entity).unwrap();
+        let cropped_rect = cropped.rect.unwrap();
+        assert!(cropped_rect.min.abs_diff_eq(Vec2::new(0.0, 0.0), 1e-3));
+        assert!(cropped_rect.max.abs_diff_eq(Vec2::new(8.0, 16.0), 1e-3));
+        let cropped_size = cropped.custom_size.unwrap();
+        assert!(cropped_size.abs_diff_eq(Vec2::new(50.0, 100.0), 1e-3));
+        assert_eq!(
+            app.world.get::<Visibility>(target_entity),
+            Some(&Visibility::Visible)
+        );
+
+        // scrolled out of view
+        run_clip(&mut app, Some(Rect::new(0.0, 0.0, 10.0, 10.0)));
+        assert_eq!(
+            app.world.get::<Visibility>(target_entity),
+            Some(&Visibility::Hidden)
+        );
+        let hidden = app.world.get::<Sprite>(target_entity).unwrap();
+        assert_eq!(hidden.rect, sprite.rect);
+        assert_eq!(hidden.custom_size, sprite.custom_size);
+
+        // back in view, the visibility from before hiding is restored
+        run_clip(&mut app, None);
+        assert_eq!(
+            app.world.get::<Visibility>(target_entity),
+            Some(&Visibility::Visible)
+        );
+        assert!(app.world.get::<ProxyUiClipped>(target_entity).is_none());
+    }
+
+    #[test]
+    fn test_crop_texture_rect() {
+        let texture_rect = Rect::new(0.0, 0.0, 16.0, 16.0);
+        let world_rect = Rect::new(-50.0, -50.0, 50.0, 50.0);
+        // keep the left and top halves
+        let crop_rect = Rect::new(-50.0, 0.0, 0.0, 50.0);
+        assert_eq!(
+            crop_texture_rect(texture_rect, world_rect, crop_rect),
+            Rect::new(0.0, 0.0, 8.0, 8.0)
+        );
+        // keep the bottom right quarter
+        let crop_rect = Rect::new(0.0, -50.0, 50.0, 0.0);
+        assert_eq!(
+            crop_texture_rect(texture_rect, world_rect, crop_rect),
+            Rect::new(8.0, 8.0, 16.0, 16.0)
+        );
+    }
+}
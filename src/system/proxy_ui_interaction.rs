@@ -0,0 +1,80 @@
+use bevy::prelude::*;
+
+use crate::prelude::*;
+
+pub(crate) fn proxy_ui_interaction(
+    mut commands: Commands,
+    mut events: EventWriter<ProxyUiInteractionEvent>,
+    proxy_ui_query: Query<(Entity, &ProxyUiTarget, &Interaction), Changed<Interaction>>,
+    proxy_target_query: Query<Option<&ProxyUiInteraction>, With<ProxyUiProxied>>,
+) {
+    for (proxy_ui_entity, proxy_ui_target, interaction) in proxy_ui_query.iter() {
+        let target_entity = proxy_ui_target.target_entity();
+        let Ok(option_previous) = proxy_target_query.get(target_entity) else {
+            continue;
+        };
+        let previous = option_previous.copied().unwrap_or_default();
+        if *previous == *interaction {
+            continue;
+        }
+        for kind in ProxyUiInteractionKind::from_change(*previous, *interaction) {
+            events.send(ProxyUiInteractionEvent {
+                proxy_ui_entity,
+                target_entity,
+                kind,
+            });
+        }
+        commands
+            .entity(target_entity)
+            .insert(ProxyUiInteraction(*interaction));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::ui::FocusPolicy;
+
+    use crate::system::proxy_ui_added::proxy_ui_added;
+    use crate::test::lib::*;
+
+    use super::*;
+
+    #[test]
+    fn test_proxy_ui_interaction() {
+        let mut app = setup_test_app();
+        app.add_event::<ProxyUiInteractionEvent>();
+        let proxied_target_entity = app.world.spawn(DummyComponent).id();
+        let proxy_ui_entity = app
+            .world
+            .spawn(ProxyUi::proxy(proxied_target_entity).with_focus_policy(FocusPolicy::Pass))
+            .id();
+        app.world.run_system_once(proxy_ui_added);
+        assert_eq!(
+            app.world.get::<FocusPolicy>(proxy_ui_entity),
+            Some(&FocusPolicy::Pass)
+        );
+
+        app.world
+            .entity_mut(proxy_ui_entity)
+            .insert(Interaction::Pressed);
+        app.world.run_system_once(proxy_ui_interaction);
+
+        assert_eq!(
+            app.world.get::<ProxyUiInteraction>(proxied_target_entity),
+            Some(&ProxyUiInteraction(Interaction::Pressed))
+        );
+        let events = app.world.resource::<Events<ProxyUiInteractionEvent>>();
+        let kinds = events
+            .iter_current_update_events()
+            .map(|event| event.kind)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            vec![
+                ProxyUiInteractionKind::Entered,
+                ProxyUiInteractionKind::Pressed
+            ]
+        );
+    }
+}
@@ -0,0 +1,75 @@
+use bevy::prelude::*;
+
+use crate::prelude::*;
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn proxy_ui_mirror(
+    proxy_ui_query: Query<
+        (&ProxyUiTarget, &ProxyUiMirror, &ProxyUiNodeState),
+        Or<(Changed<ProxyUiNodeState>, Changed<ProxyUiMirror>)>,
+    >,
+    mut mirror_target_query: Query<&mut Style, (With<ProxyUiProxied>, With<Node>)>,
+) {
+    for (proxy_ui_target, proxy_ui_mirror, node_state) in proxy_ui_query.iter() {
+        let Ok(mut style) = mirror_target_query.get_mut(proxy_ui_target.target_entity()) else {
+            continue;
+        };
+        let rect = proxy_ui_mirror.map_rect(*node_state.get_ui_rect());
+        style.position_type = PositionType::Absolute;
+        style.left = Val::Px(rect.min.x);
+        style.top = Val::Px(rect.min.y);
+        style.width = Val::Px(rect.width());
+        style.height = Val::Px(rect.height());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use crate::system::proxy_ui_added::proxy_ui_added;
+    use crate::system::proxy_ui_update::proxy_ui_update;
+    use crate::test::lib::*;
+
+    use super::*;
+
+    #[test]
+    fn test_proxy_ui_mirror() {
+        let mut app = setup_test_app();
+        let mirror_target_entity = app.world.spawn(NodeBundle::default()).id();
+        let proxy_ui_entity = app
+            .world
+            .spawn(ProxyUi::mirror(mirror_target_entity))
+            .insert(NodeBundle {
+                global_transform: GlobalTransform::from_xyz(100.0, 50.0, 0.0),
+                ..default()
+            })
+            .id();
+        app.world.run_system_once(proxy_ui_added);
+        app.world.entity_mut(proxy_ui_entity).insert(ProxyUiMirror {
+            offset: Vec2::new(10.0, 0.0),
+            ..default()
+        });
+        app.world.run_system_once(proxy_ui_update);
+        app.world.run_system_once(proxy_ui_mirror);
+
+        let style = app.world.get::<Style>(mirror_target_entity).unwrap();
+        assert_eq!(style.position_type, PositionType::Absolute);
+        assert_eq!(style.left, Val::Px(110.0));
+        assert_eq!(style.top, Val::Px(50.0));
+        assert_eq!(style.width, Val::Px(0.0));
+        assert_eq!(style.height, Val::Px(0.0));
+    }
+
+    #[test]
+    fn test_proxy_ui_mirror_requires_node() {
+        let mut app = setup_test_app();
+        let mirror_target_entity = app.world.spawn(DummyComponent).id();
+        app.world.spawn(ProxyUi::mirror(mirror_target_entity));
+        app.world.run_system_once(proxy_ui_added);
+        assert!(!app
+            .world
+            .entity(mirror_target_entity)
+            .contains::<ProxyUiProxied>());
+    }
+}
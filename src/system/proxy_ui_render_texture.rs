@@ -0,0 +1,404 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::render::camera::RenderTarget;
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat, TextureUsages};
+use bevy::render::view::RenderLayers;
+
+use crate::prelude::*;
+
+#[derive(SystemParam)]
+pub(crate) struct ProxyUiRenderTextureQueries<'w, 's> {
+    proxy_target_query: Query<'w, 's, &'static GlobalTransform, With<ProxyUiProxied>>,
+    children_query: Query<'w, 's, &'static Children>,
+    render_layers_query: Query<'w, 's, &'static RenderLayers>,
+    camera_query: Query<'w, 's, (&'static mut Transform, &'static mut Camera)>,
+}
+
+impl<'w, 's> ProxyUiRenderTextureQueries<'w, 's> {
+    /// moves the target and its descendants that aren't moved yet to `layers`,
+    /// recording their previous layers
+    ///
+    /// render layers aren't inherited, so every descendant is moved, including the ones spawned
+    /// later like scene instances
+    fn move_to_layer(
+        &self,
+        commands: &mut Commands,
+        target_entity: Entity,
+        layers: RenderLayers,
+        render_target: &mut ProxyUiRenderTarget,
+    ) {
+        let descendants = self.children_query.iter_descendants(target_entity);
+        for entity in std::iter::once(target_entity).chain(descendants) {
+            if render_target.previous_layers.contains_key(&entity) {
+                continue;
+            }
+            let previous_layers = self.render_layers_query.get(entity).ok().copied();
+            render_target
+                .previous_layers
+                .insert(entity, previous_layers);
+            commands.entity(entity).insert(layers);
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn proxy_ui_render_texture(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    mut next_camera_order: Local<isize>,
+    mut removed_render_textures: RemovedComponents<ProxyUiRenderTexture>,
+    mut proxy_ui_query: Query<(
+        Entity,
+        &ProxyUiTarget,
+        &ProxyUiNodeState,
+        Ref<ProxyUiRenderTexture>,
+        Option<&mut ProxyUiRenderTarget>,
+        Option<&BackgroundColor>,
+    )>,
+    mut queries: ProxyUiRenderTextureQueries,
+) {
+    // removing only the render texture keeps the link, clean up what we created
+    for proxy_ui_entity in removed_render_textures.read() {
+        commands.add(move |world: &mut World| {
+            let Some(mut proxy_ui_entity_mut) = world.get_entity_mut(proxy_ui_entity) else {
+                return;
+            };
+            if proxy_ui_entity_mut.contains::<ProxyUiRenderTexture>() {
+                return;
+            }
+            let Some(render_target) = proxy_ui_entity_mut.take::<ProxyUiRenderTarget>() else {
+                return;
+            };
+            render_target.release(proxy_ui_entity, world);
+        });
+    }
+
+    for (
+        proxy_ui_entity,
+        proxy_ui_target,
+        node_state,
+        render_texture,
+        option_render_target,
+        option_background_color,
+    ) in proxy_ui_query.iter_mut()
+    {
+        let target_entity = proxy_ui_target.target_entity();
+        let Ok(target_global_transform) = queries.proxy_target_query.get(target_entity) else {
+            continue;
+        };
+        let size = image_size(node_state);
+        let camera_transform = camera_transform(
+            target_global_transform.translation(),
+            render_texture.camera_offset,
+        );
+        let layers = RenderLayers::layer(render_texture.layer);
+        let Some(mut render_target) = option_render_target else {
+            let image = images.add(create_image(size));
+            // render before the main camera that draws the ui,
+            // every render texture camera gets its own order
+            *next_camera_order -= 1;
+            let camera_entity = commands
+                .spawn((
+                    Camera3dBundle {
+                        camera: Camera {
+                            order: *next_camera_order,
+                            target: RenderTarget::Image(image.clone()),
+                            clear_color: ClearColorConfig::Custom(render_texture.clear_color),
+                            ..default()
+                        },
+                        transform: camera_transform,
+                        ..default()
+                    },
+                    layers,
+                ))
+                .id();
+            let mut render_target = ProxyUiRenderTarget {
+                camera_entity,
+                image: image.clone(),
+                previous_layers: default(),
+                previous_background_color: option_background_color.copied(),
+            };
+            queries.move_to_layer(&mut commands, target_entity, layers, &mut render_target);
+            // nodes are transparent by default and transparent nodes aren't drawn,
+            // the image is tinted by the background color
+            commands.entity(proxy_ui_entity).insert((
+                UiImage::new(image),
+                BackgroundColor(Color::WHITE),
+                render_target,
+            ));
+            continue;
+        };
+
+        if render_texture.is_changed() {
+            for entity in render_target.previous_layers.keys() {
+                commands.entity(*entity).insert(layers);
+            }
+            commands.entity(render_target.camera_entity).insert(layers);
+        }
+        queries.move_to_layer(&mut commands, target_entity, layers, &mut render_target);
+        // get_mut marks the image as modified, which recreates the texture
+        let resized = images
+            .get(&render_target.image)
+            .is_some_and(|image| image.texture_descriptor.size != size);
+        if resized {
+            if let Some(image) = images.get_mut(&render_target.image) {
+                image.resize(size);
+            }
+        }
+        if let Ok((mut transform, mut camera)) =
+            queries.camera_query.get_mut(render_target.camera_entity)
+        {
+            transform.set_if_neq(camera_transform);
+            if render_texture.is_changed() {
+                camera.clear_color = ClearColorConfig::Custom(render_texture.clear_color);
+            }
+        }
+    }
+}
+
+/// the camera at `camera_offset` from the target looking at it, offsets along the y axis
+/// (top down or bottom up) use z as up since y can't be
+fn camera_transform(target_position: Vec3, camera_offset: Vec3) -> Transform {
+    let up = if camera_offset
+        .normalize_or_zero()
+        .cross(Vec3::Y)
+        .length_squared()
+        <= f32::EPSILON
+    {
+        Vec3::Z
+    } else {
+        Vec3::Y
+    };
+    Transform::from_translation(target_position + camera_offset).looking_at(target_position, up)
+}
+
+/// the physical size of the node, images can't be empty
+fn image_size(node_state: &ProxyUiNodeState) -> Extent3d {
+    let size = node_state
+        .get_physical_rect()
+        .size()
+        .round()
+        .as_uvec2()
+        .max(UVec2::ONE);
+    Extent3d {
+        width: size.x,
+        height: size.y,
+        depth_or_array_layers: 1,
+    }
+}
+
+fn create_image(size: Extent3d) -> Image {
+    let mut image = Image::new_fill(
+        size,
+        TextureDimension::D2,
+        &[0, 0, 0, 0],
+        TextureFormat::Bgra8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    image.texture_descriptor.usage =
+        TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST | TextureUsages::RENDER_ATTACHMENT;
+    image
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use crate::resource::proxy_ui_entity_map::ProxyUiEntityMap;
+    use crate::system::proxy_ui_added::proxy_ui_added;
+    use crate::system::proxy_ui_update::proxy_ui_update;
+    use crate::test::lib::*;
+
+    use super::*;
+
+    fn spawn_render_texture(app: &mut App, proxied_target_entity: Entity, size: Vec2) -> Entity {
+        app.world
+            .spawn((
+                ProxyUi::proxy(proxied_target_entity),
+                ProxyUiRenderTexture::new(3),
+                NodeBundle {
+                    node: sized_node(size),
+                    ..default()
+                },
+            ))
+            .id()
+    }
+
+    fn link_render_texture(app: &mut App, proxied_target_entity: Entity, size: Vec2) -> Entity {
+        let proxy_ui_entity = spawn_render_texture(app, proxied_target_entity, size);
+        app.world.run_system_once(proxy_ui_added);
+        app.world.run_system_once(proxy_ui_update);
+        app.world.run_system_once(proxy_ui_render_texture);
+        proxy_ui_entity
+    }
+
+    fn render_target(app: &App, proxy_ui_entity: Entity) -> (Entity, Handle<Image>) {
+        let render_target = app
+            .world
+            .get::<ProxyUiRenderTarget>(proxy_ui_entity)
+            .unwrap();
+        (render_target.camera_entity, render_target.image.clone())
+    }
+
+    #[test]
+    fn test_proxy_ui_render_texture() {
+        let mut app = setup_test_app();
+        app.init_resource::<Assets<Image>>();
+        let proxied_target_entity = app
+            .world
+            .spawn((SpatialBundle::default(), RenderLayers::layer(5)))
+            .id();
+        let child_entity = app
+            .world
+            .spawn(SpatialBundle::default())
+            .set_parent(proxied_target_entity)
+            .id();
+        let proxy_ui_entity = link_render_texture(&mut app, proxied_target_entity, Vec2::ONE);
+
+        let (camera_entity, image) = render_target(&app, proxy_ui_entity);
+        assert_eq!(
+            app.world.get::<UiImage>(proxy_ui_entity).unwrap().texture,
+            image
+        );
+        assert!(app.world.resource::<Assets<Image>>().contains(&image));
+        // the image is only drawn when the node isn't transparent
+        assert_eq!(
+            app.world.get::<BackgroundColor>(proxy_ui_entity).unwrap().0,
+            Color::WHITE
+        );
+        for entity in [proxied_target_entity, child_entity] {
+            assert_eq!(
+                app.world.get::<RenderLayers>(entity),
+                Some(&RenderLayers::layer(3))
+            );
+        }
+
+        // descendants spawned later, like scene instances, are moved as well
+        let late_child_entity = app
+            .world
+            .spawn(SpatialBundle::default())
+            .set_parent(child_entity)
+            .id();
+        app.world.run_system_once(proxy_ui_render_texture);
+        assert_eq!(
+            app.world.get::<RenderLayers>(late_child_entity),
+            Some(&RenderLayers::layer(3))
+        );
+
+        app.world
+            .run_system_once(|mut commands: Commands, map: Res<ProxyUiEntityMap>| {
+                let (_, proxy_ui_entity) = map.iter().next().unwrap();
+                commands.unlink_proxy_ui(proxy_ui_entity);
+            });
+        assert!(app.world.get_entity(camera_entity).is_none());
+        assert!(!app.world.resource::<Assets<Image>>().contains(&image));
+        assert!(!app.world.entity(proxy_ui_entity).contains::<UiImage>());
+        assert_eq!(
+            app.world.get::<BackgroundColor>(proxy_ui_entity).unwrap().0,
+            Color::NONE
+        );
+        // the previous layers are restored
+        assert_eq!(
+            app.world.get::<RenderLayers>(proxied_target_entity),
+            Some(&RenderLayers::layer(5))
+        );
+        for entity in [child_entity, late_child_entity] {
+            assert!(!app.world.entity(entity).contains::<RenderLayers>());
+        }
+    }
+
+    #[test]
+    fn test_proxy_ui_render_texture_top_down() {
+        let mut app = setup_test_app();
+        app.init_resource::<Assets<Image>>();
+        let proxied_target_entity = app.world.spawn(SpatialBundle::default()).id();
+        let proxy_ui_entity = app
+            .world
+            .spawn((
+                ProxyUi::proxy(proxied_target_entity),
+                ProxyUiRenderTexture::new(3).with_camera_offset(Vec3::new(0.0, 10.0, 0.0)),
+                NodeBundle::default(),
+            ))
+            .id();
+        app.world.run_system_once(proxy_ui_added);
+        app.world.run_system_once(proxy_ui_update);
+        app.world.run_system_once(proxy_ui_render_texture);
+
+        let (camera_entity, _) = render_target(&app, proxy_ui_entity);
+        let transform = app.world.get::<Transform>(camera_entity).unwrap();
+        assert!(transform.rotation.is_finite());
+        assert_eq!(transform.translation, Vec3::new(0.0, 10.0, 0.0));
+        // looking straight down at the target
+        assert!(transform.forward().abs_diff_eq(Vec3::NEG_Y, 1e-5));
+    }
+
+    #[test]
+    fn test_proxy_ui_render_texture_resize() {
+        let mut app = setup_test_app();
+        app.init_resource::<Assets<Image>>()
+            .add_event::<AssetEvent<Image>>();
+        let proxied_target_entity = app.world.spawn(SpatialBundle::default()).id();
+        let proxy_ui_entity =
+            link_render_texture(&mut app, proxied_target_entity, Vec2::new(200.0, 100.0));
+        let (_, image) = render_target(&app, proxy_ui_entity);
+        let modified_count = |app: &mut App| {
+            app.world.run_system_once(Assets::<Image>::asset_events);
+            app.world
+                .resource_mut::<Events<AssetEvent<Image>>>()
+                .drain()
+                .filter(|event| event.is_modified(&image))
+                .count()
+        };
+        modified_count(&mut app);
+
+        // the image is left alone while the node keeps its size
+        app.world.run_system_once(proxy_ui_update);
+        app.world.run_system_once(proxy_ui_render_texture);
+        app.world.run_system_once(proxy_ui_render_texture);
+        assert_eq!(modified_count(&mut app), 0);
+
+        app.world
+            .entity_mut(proxy_ui_entity)
+            .insert(sized_node(Vec2::new(300.0, 150.0)));
+        app.world.run_system_once(proxy_ui_update);
+        app.world.run_system_once(proxy_ui_render_texture);
+        assert_eq!(modified_count(&mut app), 1);
+        let images = app.world.resource::<Assets<Image>>();
+        assert_eq!(images.get(&image).unwrap().size(), UVec2::new(300, 150));
+    }
+
+    #[test]
+    fn test_proxy_ui_render_texture_removed() {
+        let mut app = setup_test_app();
+        app.init_resource::<Assets<Image>>();
+        let first_target_entity = app.world.spawn(SpatialBundle::default()).id();
+        let second_target_entity = app.world.spawn(SpatialBundle::default()).id();
+        let first_entity = spawn_render_texture(&mut app, first_target_entity, Vec2::ONE);
+        let second_entity = link_render_texture(&mut app, second_target_entity, Vec2::ONE);
+        let (first_camera_entity, first_image) = render_target(&app, first_entity);
+        let (second_camera_entity, _) = render_target(&app, second_entity);
+        // every camera gets its own order
+        assert_ne!(
+            app.world.get::<Camera>(first_camera_entity).unwrap().order,
+            app.world.get::<Camera>(second_camera_entity).unwrap().order
+        );
+
+        // removing only the render texture keeps the link but cleans up
+        app.world
+            .entity_mut(first_entity)
+            .remove::<ProxyUiRenderTexture>();
+        app.world.run_system_once(proxy_ui_render_texture);
+        assert!(app.world.get_entity(first_camera_entity).is_none());
+        assert!(!app.world.resource::<Assets<Image>>().contains(&first_image));
+        let first_entity_ref = app.world.entity(first_entity);
+        assert!(!first_entity_ref.contains::<UiImage>());
+        assert!(!first_entity_ref.contains::<ProxyUiRenderTarget>());
+        assert!(first_entity_ref.contains::<ProxyUiTarget>());
+        assert!(!app
+            .world
+            .entity(first_target_entity)
+            .contains::<RenderLayers>());
+        assert!(app.world.get_entity(second_camera_entity).is_some());
+    }
+}
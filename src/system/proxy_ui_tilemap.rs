@@ -0,0 +1,175 @@
+use bevy::prelude::*;
+use bevy_ecs_tilemap::prelude::*;
+
+use crate::prelude::*;
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn proxy_ui_tilemap(
+    mut commands: Commands,
+    query: Query<
+        (
+            Entity,
+            &ProxyUiTilemap,
+            &ProxyUiNodeState,
+            &TilemapSize,
+            &TilemapGridSize,
+            &TilemapType,
+            Option<&ProxyUiAnchor>,
+            Option<&Transform>,
+        ),
+        (With<ProxyUiProxied>, Changed<ProxyUiNodeState>),
+    >,
+) {
+    for (
+        entity,
+        proxy_ui_tilemap,
+        node_state,
+        tilemap_size,
+        grid_size,
+        map_type,
+        anchor,
+        transform,
+    ) in query.iter()
+    {
+        let z = node_state.get_world_z().unwrap_or_else(|| {
+            transform
+                .map(|transform| transform.translation.z)
+                .unwrap_or_default()
+        });
+        let tilemap_bounds = tilemap_bounds(tilemap_size, grid_size, map_type);
+        let tilemap_transform = proxy_ui_tilemap.fit.transform(
+            tilemap_bounds,
+            node_state,
+            &anchor.copied().unwrap_or_default(),
+            z,
+        );
+
+        // carry over the rotation of the node
+        let tilemap_transform =
+            node_state.orient(tilemap_transform, node_state.get_world_rect().center());
+
+        let mut entity_commands = commands.entity(entity);
+        node_state.insert_transform(&mut entity_commands, tilemap_transform);
+        entity_commands.insert(node_state.get_computed_visibility());
+    }
+}
+
+/// returns the bounds of the tilemap in its local space
+///
+/// tile centers are positioned relative to the tilemap origin depending on the map type,
+/// so the tiles at the edges are used to find the extents. Hex offset and staggered maps shift
+/// every other row or column by half a tile, so the second row and column from each edge can
+/// stick out past the corner tiles and are included as well
+fn tilemap_bounds(
+    tilemap_size: &TilemapSize,
+    grid_size: &TilemapGridSize,
+    map_type: &TilemapType,
+) -> Rect {
+    let max_x = tilemap_size.x.saturating_sub(1);
+    let max_y = tilemap_size.y.saturating_sub(1);
+    let edges = |max: u32| [0, 1.min(max), max.saturating_sub(1), max];
+    let tile_size = Vec2::new(grid_size.x, grid_size.y);
+    edges(max_x)
+        .into_iter()
+        .flat_map(|x| edges(max_y).into_iter().map(move |y| TilePos { x, y }))
+        .map(|tile_pos| tile_pos.center_in_world(grid_size, map_type))
+        .map(|center| Rect::from_center_size(center, tile_size))
+        .reduce(|a, b| a.union(b))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::sprite::Anchor;
+
+    use crate::system::proxy_ui_added::proxy_ui_added;
+    use crate::system::proxy_ui_update::proxy_ui_update;
+    use crate::test::lib::*;
+
+    use super::*;
+
+    /// fits a 4x2 tilemap of 16x16 tiles into a 200x200 node at the center of the window,
+    /// the world rect of the node is (-100, -100) (100, 100)
+    fn fit_tilemap(option_anchor: Option<ProxyUiAnchor>) -> GlobalTransform {
+        let mut app = setup_test_app();
+        spawn_window_camera(&mut app);
+        let tilemap_entity = app
+            .world
+            .spawn((
+                TilemapSize { x: 4, y: 2 },
+                TilemapGridSize { x: 16.0, y: 16.0 },
+                TilemapType::Square,
+                ProxyUiTilemap::new(ProxyUiFit::Contain),
+            ))
+            .id();
+        if let Some(anchor) = option_anchor {
+            app.world.entity_mut(tilemap_entity).insert(anchor);
+        }
+        app.world.spawn((
+            ProxyUi::proxy(tilemap_entity),
+            NodeBundle {
+                node: sized_node(Vec2::splat(200.0)),
+                global_transform: GlobalTransform::from_xyz(640.0, 360.0, 0.0),
+                ..default()
+            },
+        ));
+        app.world.run_system_once(proxy_ui_added);
+        app.world.run_system_once(proxy_ui_update);
+        app.world.run_system_once(proxy_ui_tilemap);
+        *app.world.get::<GlobalTransform>(tilemap_entity).unwrap()
+    }
+
+    #[test]
+    fn test_proxy_ui_tilemap() {
+        // tile centers span (0, 0) (48, 16), so the tilemap covers (-8, -8) (56, 24)
+        // and is scaled by 200 / 64
+        let transform = fit_tilemap(None).compute_transform();
+        assert!(transform
+            .scale
+            .abs_diff_eq(Vec3::new(3.125, 3.125, 1.0), 1e-4));
+        assert!(transform
+            .translation
+            .abs_diff_eq(Vec3::new(-75.0, -25.0, 0.0), 1e-3));
+
+        let transform =
+            fit_tilemap(Some(ProxyUiAnchor::new(Anchor::BottomLeft))).compute_transform();
+        assert!(transform
+            .translation
+            .abs_diff_eq(Vec3::new(-75.0, -75.0, 0.0), 1e-3));
+    }
+
+    #[test]
+    fn test_tilemap_bounds() {
+        let tilemap_size = TilemapSize { x: 5, y: 4 };
+        let grid_size = TilemapGridSize { x: 16.0, y: 16.0 };
+        for map_type in [
+            TilemapType::Square,
+            TilemapType::Isometric(IsoCoordSystem::Diamond),
+            TilemapType::Isometric(IsoCoordSystem::Staggered),
+            TilemapType::Hexagon(HexCoordSystem::Row),
+            TilemapType::Hexagon(HexCoordSystem::RowOdd),
+            TilemapType::Hexagon(HexCoordSystem::RowEven),
+            TilemapType::Hexagon(HexCoordSystem::Column),
+            TilemapType::Hexagon(HexCoordSystem::ColumnOdd),
+            TilemapType::Hexagon(HexCoordSystem::ColumnEven),
+        ] {
+            // the bounds cover every tile of the map
+            let all_tiles = (0..tilemap_size.x)
+                .flat_map(|x| (0..tilemap_size.y).map(move |y| TilePos { x, y }))
+                .map(|tile_pos| {
+                    Rect::from_center_size(
+                        tile_pos.center_in_world(&grid_size, &map_type),
+                        Vec2::new(grid_size.x, grid_size.y),
+                    )
+                })
+                .reduce(|a, b| a.union(b))
+                .unwrap();
+            assert_eq!(
+                tilemap_bounds(&tilemap_size, &grid_size, &map_type),
+                all_tiles,
+                "{map_type:?}"
+            );
+        }
+    }
+}
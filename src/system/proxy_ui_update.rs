@@ -1,9 +1,13 @@
 use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
+use bevy::ui::UiStack;
 use bevy::window::PrimaryWindow;
 
+use crate::error::ProxyUiStateError;
 use crate::prelude::*;
-use crate::util::ui::ui_to_world;
+use crate::system_param::proxy_ui_cameras::ProxyUiCameras;
+use crate::util::style::inset_rect;
+use crate::util::ui::{scale_rect, snap_to_pixel_grid, ui_quad_corners, ui_to_world};
 
 #[allow(clippy::type_complexity)]
 #[derive(SystemParam)]
@@ -21,31 +25,39 @@ pub(crate) struct ProxyUiUpdateQueries<'w, 's> {
             &'static GlobalTransform,
             &'static Visibility,
             &'static ViewVisibility,
+            Option<&'static CalculatedClip>,
+            (
+                Option<&'static ProxyUiPixelSnap>,
+                Option<&'static ProxyUiBox>,
+                Option<&'static ProxyUiSpace>,
+                Option<&'static ProxyUiClip>,
+            ),
+            Option<&'static ProxyUiMirror>,
+            Option<&'static Parent>,
+        ),
+        (
+            With<ProxyUiTarget>,
+            Without<ProxyUiProxied>,
+            Without<ProxyUiPaused>,
+            Without<WorldProxyUi>,
         ),
-        (With<ProxyUiTarget>, Without<ProxyUiProxied>),
     >,
+    parent_node_query: Query<'w, 's, &'static Node>,
     proxy_target_query: Query<
         'w,
         's,
-        (Entity, Option<&'static Node>),
+        (Entity, Option<&'static Node>, Option<&'static Parent>),
         (With<ProxyUiProxied>, Without<ProxyUiTarget>),
     >,
     primary_window_query: Query<'w, 's, &'static Window, With<PrimaryWindow>>,
-    camera_query: Query<
-        'w,
-        's,
-        (
-            &'static Camera,
-            &'static Transform,
-            &'static OrthographicProjection,
-        ),
-        With<Camera>,
-    >,
+    cameras: ProxyUiCameras<'w, 's>,
 }
 
 pub(crate) fn proxy_ui_update(
     mut commands: Commands,
     ui_scale: Option<Res<UiScale>>,
+    ui_stack: Option<Res<UiStack>>,
+    z_range: Option<Res<ProxyUiZRange>>,
     queries: ProxyUiUpdateQueries,
 ) {
     for (
@@ -58,33 +70,15 @@ pub(crate) fn proxy_ui_update(
         global_transform,
         visibility,
         view_visibility,
+        option_calculated_clip,
+        (option_pixel_snap, option_proxy_ui_box, option_space, option_clip),
+        option_mirror,
+        option_parent,
     ) in queries.proxy_ui_query.iter()
     {
-        let ui_rect = node.logical_rect(global_transform);
-        // TODO: this should be deferred until a change is detected
-        let world_rect = {
-            let window_query_result = match queries.primary_window_query.get_single() {
-                Ok(window) => Some(window),
-                Err(err) => {
-                    warn!("failed to get primary window {err:?}");
-                    None
-                }
-            };
-            if let Some(window) = window_query_result {
-                if let Some((_camera, camera_transform, camera_projection)) =
-                    queries.camera_query.iter().find(|(c, ..)| c.is_active)
-                {
-                    Rect {
-                        min: ui_to_world(ui_rect.min, window, camera_transform, camera_projection),
-                        max: ui_to_world(ui_rect.max, window, camera_transform, camera_projection),
-                    }
-                } else {
-                    Rect::default()
-                }
-            } else {
-                Rect::default()
-            }
-        };
+        let pixel_snap = option_pixel_snap.copied().unwrap_or_default();
+        let space = option_space.copied().unwrap_or_default();
+        let clip = option_clip.copied().unwrap_or_default();
 
         // Res does not implement Default so we can't just do unwrap_or_default()
         let ui_scale = if let Some(ref ui_scale) = ui_scale {
@@ -93,15 +87,111 @@ pub(crate) fn proxy_ui_update(
             UiScale::default().0
         };
 
+        let window_query_result = match queries.primary_window_query.get_single() {
+            Ok(window) => Some(window),
+            Err(err) => {
+                warn!("failed to get primary window {err:?}");
+                None
+            }
+        };
+
+        let ui_boxes = {
+            let viewport_size = window_query_result
+                .map(|window| Vec2::new(window.width(), window.height()) / ui_scale)
+                .unwrap_or_default();
+            // percentages resolve against the parent, or the viewport for root nodes
+            let parent_width = option_parent
+                .and_then(|parent| queries.parent_node_query.get(parent.get()).ok())
+                .map(|parent_node| parent_node.size().x)
+                .unwrap_or(viewport_size.x);
+            let border_box = node.logical_rect(global_transform);
+            let padding_box = inset_rect(border_box, &style.border, parent_width, viewport_size);
+            let content_box = inset_rect(padding_box, &style.padding, parent_width, viewport_size);
+            ProxyUiBoxRects {
+                border_box,
+                padding_box,
+                content_box,
+            }
+        };
+
+        let scale_factor = window_query_result
+            .map(|window| window.scale_factor())
+            .unwrap_or(1.0);
+
+        // every conversion goes through the camera rendering the node,
+        // with split screen every viewport has its own
+        let ui_camera = queries.cameras.node_camera(proxy_ui_entity);
+
+        // TODO: this should be deferred until a change is detected
+        let to_world_quad = |ui_rect: Rect, node_global_transform: &GlobalTransform| {
+            let Some(ui_camera) = &ui_camera else {
+                return ProxyUiQuad::default();
+            };
+            let ui_rect = if pixel_snap.snap_to_pixels {
+                snap_to_pixel_grid(ui_rect, scale_factor * ui_scale)
+            } else {
+                ui_rect
+            };
+            // camera local rects don't change when the camera moves
+            let camera_transform = match space {
+                ProxyUiSpace::World => ui_camera.transform,
+                ProxyUiSpace::CameraLocal => &Transform::IDENTITY,
+            };
+            // ui_to_world expects logical pixels relative to the viewport, like node positions
+            let viewport_size = ui_camera.viewport_rect.size();
+            ProxyUiQuad::from_corners(ui_quad_corners(ui_rect, node_global_transform).map(
+                |corner| {
+                    ui_to_world(
+                        corner * ui_scale,
+                        viewport_size,
+                        camera_transform,
+                        &ui_camera.projection,
+                    )
+                },
+            ))
+        };
+
+        let target_box = option_proxy_ui_box.copied().unwrap_or_default();
+        let world_quad = to_world_quad(ui_boxes.get(target_box), global_transform);
+        let world_boxes = ui_boxes.map(|ui_rect| to_world_quad(ui_rect, global_transform).rect());
+        // clip rects are axis aligned in ui space regardless of the node's rotation
+        let ui_clip = option_calculated_clip.map(|calculated_clip| calculated_clip.clip);
+        let world_clip =
+            ui_clip.map(|ui_clip| to_world_quad(ui_clip, &GlobalTransform::IDENTITY).rect());
+
+        let ui_rect = ui_boxes.get(target_box);
+        let logical_rect = scale_rect(ui_rect, ui_scale);
+        let stack_index = node.stack_index();
+        let world_z = z_range.as_ref().map(|z_range| {
+            let stack_len = ui_stack
+                .as_ref()
+                .map(|ui_stack| ui_stack.uinodes.len())
+                .unwrap_or_default();
+            z_range.z(stack_index, stack_len)
+        });
         let new_node_state = ProxyUiNodeState {
             ui_rect,
-            world_rect,
+            logical_rect,
+            physical_rect: scale_rect(logical_rect, scale_factor),
+            world_rect: world_quad.rect(),
+            world_quad,
+            target_box,
+            ui_boxes,
+            world_boxes,
+            ui_clip,
+            world_clip,
+            stack_index,
+            world_z,
             global_transform: *global_transform,
             transform: *transform,
             style: style.clone(),
             ui_scale,
+            scale_factor,
             visibility: *visibility,
             view_visibility: *view_visibility,
+            pixel_snap,
+            space,
+            clip,
         };
 
         let proxy_ui_node_state_has_changed = match option_proxy_ui_node_state {
@@ -109,22 +199,50 @@ pub(crate) fn proxy_ui_update(
             _ => true,
         };
 
-        let (proxied_entity, option_bad_node) = queries
+        let Ok((proxied_entity, option_bad_node, option_target_parent)) = queries
             .proxy_target_query
             .get(ui_proxy_target.target_entity())
-            .unwrap_or_else(|err| {
-                panic!(
-                    "failed to find proxy target: {:?} - {err:?}",
-                    ui_proxy_target.target_entity()
-                )
-            });
-
-        if option_bad_node.is_some() {
+        else {
+            // despawned targets can't come back, `ProxyUiErrorPolicy` only covers the initial link
+            let err = ProxyUiStateError::MissingTarget {
+                proxy_ui_entity,
+                target_entity: ui_proxy_target.target_entity(),
+            };
+            warn!("{err}, removing proxy");
+            commands.unlink_proxy_ui(proxy_ui_entity);
+            continue;
+        };
+
+        if option_mirror.is_some() {
+            if option_bad_node.is_none() {
+                warn!("expected Ui Node on mirror target! removing proxy");
+                commands.unlink_proxy_ui(proxy_ui_entity);
+                continue;
+            }
+        } else if option_bad_node.is_some() {
             warn!("unexpected Ui Node found on proxy target! removing proxy");
-            commands.entity(proxied_entity).remove::<ProxyUiProxied>();
-            commands.entity(proxy_ui_entity).remove::<ProxyUiTarget>();
+            commands.unlink_proxy_ui(proxy_ui_entity);
             continue;
         }
+
+        match space {
+            ProxyUiSpace::CameraLocal => {
+                if let Some(camera_entity) = ui_camera.as_ref().map(|ui_camera| ui_camera.entity) {
+                    if option_target_parent.map(|parent| parent.get()) != Some(camera_entity) {
+                        debug!("parenting proxy target {proxied_entity:?} to {camera_entity:?}");
+                        commands.entity(camera_entity).add_child(proxied_entity);
+                    }
+                }
+            }
+            ProxyUiSpace::World => {
+                let was_camera_local = option_proxy_ui_node_state
+                    .is_some_and(|state| state.space == ProxyUiSpace::CameraLocal);
+                if was_camera_local {
+                    commands.entity(proxied_entity).remove_parent();
+                }
+            }
+        }
+
         if proxy_ui_node_state_has_changed {
             debug!("proxy ui node state updated: {new_node_state:?}");
             commands
@@ -139,7 +257,9 @@ pub(crate) fn proxy_ui_update(
 mod tests {
     use bevy::ecs::system::RunSystemOnce;
     use bevy::prelude::*;
+    use bevy::render::camera::{CameraProjection, Viewport};
 
+    use crate::resource::proxy_ui_entity_map::ProxyUiEntityMap;
     use crate::system::proxy_ui_added::proxy_ui_added;
     use crate::test::lib::*;
 
@@ -171,4 +291,157 @@ mod tests {
             assert_eq!(proxied_node_state, ui_node_state);
         }
     }
+
+    #[test]
+    fn test_proxy_ui_update_camera_local() {
+        let mut app = setup_test_app();
+        app.world.spawn((Window::default(), PrimaryWindow));
+        let camera_entity = app.world.spawn(Camera2dBundle::default()).id();
+        app.world.run_system_once(set_scene);
+        app.world.run_system_once(
+            |mut commands: Commands, query: Query<Entity, With<ProxyUi>>| {
+                for entity in query.iter() {
+                    commands.entity(entity).insert(ProxyUiSpace::CameraLocal);
+                }
+            },
+        );
+        app.world.run_system_once(proxy_ui_added);
+        app.world.run_system_once(proxy_ui_update);
+
+        let mut query_proxy_ui_proxied = app
+            .world
+            .query_filtered::<(&Parent, &ProxyUiNodeState), With<ProxyUiProxied>>();
+        let (parent, node_state) = query_proxy_ui_proxied.single(&app.world);
+        assert_eq!(parent.get(), camera_entity);
+        assert_eq!(node_state.get_space(), &ProxyUiSpace::CameraLocal);
+    }
+
+    #[test]
+    fn test_proxy_ui_update_split_screen() {
+        let mut app = setup_test_app();
+        app.world.spawn((Window::default(), PrimaryWindow));
+        // two side by side viewports of the 1280x720 window, each with a node in its center
+        let proxied_target_entities = [(0, 0.0), (1, 1000.0)].map(|(index, camera_x)| {
+            let mut projection = OrthographicProjection::default();
+            projection.update(640.0, 720.0);
+            let camera_entity = app
+                .world
+                .spawn(Camera2dBundle {
+                    camera: Camera {
+                        order: index,
+                        viewport: Some(Viewport {
+                            physical_position: UVec2::new(640 * index as u32, 0),
+                            physical_size: UVec2::new(640, 720),
+                            ..default()
+                        }),
+                        ..default()
+                    },
+                    projection,
+                    transform: Transform::from_xyz(camera_x, 0.0, 0.0),
+                    ..default()
+                })
+                .id();
+            let proxied_target_entity = app.world.spawn(DummyComponent).id();
+            app.world.spawn((
+                ProxyUi::proxy(proxied_target_entity),
+                TargetCamera(camera_entity),
+                NodeBundle {
+                    node: sized_node(Vec2::splat(100.0)),
+                    global_transform: GlobalTransform::from_xyz(320.0, 360.0, 0.0),
+                    ..default()
+                },
+            ));
+            (proxied_target_entity, camera_x)
+        });
+        app.world.run_system_once(proxy_ui_added);
+        app.world.run_system_once(proxy_ui_update);
+
+        // node positions are relative to their own camera's viewport
+        for (proxied_target_entity, camera_x) in proxied_target_entities {
+            let node_state = app
+                .world
+                .get::<ProxyUiNodeState>(proxied_target_entity)
+                .unwrap();
+            let world_rect = node_state.get_world_rect();
+            let expected = Rect::from_center_size(Vec2::new(camera_x, 0.0), Vec2::splat(100.0));
+            assert!(world_rect.min.abs_diff_eq(expected.min, 1e-3));
+            assert!(world_rect.max.abs_diff_eq(expected.max, 1e-3));
+        }
+    }
+
+    #[test]
+    fn test_proxy_ui_update_despawned_target() {
+        let mut app = setup_test_app();
+        let proxied_target_entity = app.world.spawn(DummyComponent).id();
+        let proxy_ui_entity = app.world.spawn(ProxyUi::proxy(proxied_target_entity)).id();
+        app.world.run_system_once(proxy_ui_added);
+        app.world.run_system_once(proxy_ui_update);
+        app.world.despawn(proxied_target_entity);
+        app.world.run_system_once(proxy_ui_update);
+
+        assert!(!app
+            .world
+            .entity(proxy_ui_entity)
+            .contains::<ProxyUiTarget>());
+        assert!(!app
+            .world
+            .resource::<ProxyUiEntityMap>()
+            .contains_proxy(&proxy_ui_entity));
+    }
+
+    #[test]
+    fn test_proxy_ui_update_paused() {
+        let mut app = setup_test_app();
+        let proxied_target_entity = app.world.spawn(DummyComponent).id();
+        let proxy_ui_entity = app
+            .world
+            .spawn(ProxyUi::proxy(proxied_target_entity))
+            .insert(NodeBundle::default())
+            .id();
+        app.world.run_system_once(proxy_ui_added);
+        app.world.run_system_once(proxy_ui_update);
+        let node_state = app
+            .world
+            .get::<ProxyUiNodeState>(proxied_target_entity)
+            .cloned();
+
+        app.world
+            .entity_mut(proxy_ui_entity)
+            .insert((ProxyUiPaused, GlobalTransform::from_xyz(100.0, 100.0, 0.0)));
+        app.world.run_system_once(proxy_ui_update);
+        assert_eq!(
+            app.world
+                .get::<ProxyUiNodeState>(proxied_target_entity)
+                .cloned(),
+            node_state
+        );
+
+        app.world
+            .entity_mut(proxy_ui_entity)
+            .remove::<ProxyUiPaused>();
+        app.world.run_system_once(proxy_ui_update);
+        assert_ne!(
+            app.world
+                .get::<ProxyUiNodeState>(proxied_target_entity)
+                .cloned(),
+            node_state
+        );
+    }
+
+    #[test]
+    fn test_proxy_ui_update_z_range() {
+        let mut app = setup_test_app();
+        let proxied_target_entity = app.world.spawn(DummyComponent).id();
+        app.world.spawn(ProxyUi::proxy(proxied_target_entity));
+        app.world.run_system_once(proxy_ui_added);
+        app.world.run_system_once(proxy_ui_update);
+        let node_state = app.world.get::<ProxyUiNodeState>(proxied_target_entity);
+        assert_eq!(node_state.unwrap().get_world_z(), None);
+
+        app.world.insert_resource(ProxyUiZRange::new(5.0, 10.0));
+        app.world.run_system_once(proxy_ui_update);
+        let node_state = app.world.get::<ProxyUiNodeState>(proxied_target_entity);
+        assert_eq!(node_state.unwrap().get_world_z(), Some(5.0));
+        assert_eq!(node_state.unwrap().get_world_transform().translation.z, 5.0);
+    }
 }
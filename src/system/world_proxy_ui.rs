@@ -0,0 +1,432 @@
+use std::cmp::Reverse;
+
+use bevy::prelude::*;
+
+use crate::error::ProxyUiStateError;
+use crate::prelude::*;
+use crate::resource::proxy_ui_entity_map::ProxyUiEntityMap;
+use crate::system::proxy_ui_added::validate_state;
+
+pub(crate) fn world_proxy_ui_added(
+    mut commands: Commands,
+    mut proxy_ui_entity_map: ResMut<ProxyUiEntityMap>,
+    world_proxy_ui_query: Query<(Entity, &WorldProxyUi), Added<WorldProxyUi>>,
+    proxy_target_query: Query<(), With<GlobalTransform>>,
+) {
+    for (proxy_ui_entity, world_proxy_ui) in world_proxy_ui_query.iter() {
+        let proxy_target_entity = world_proxy_ui.target_entity;
+        let validation =
+            validate_state(&proxy_ui_entity_map, &proxy_ui_entity, proxy_target_entity).and_then(
+                |_| {
+                    proxy_target_query.get(proxy_target_entity).map_err(|_| {
+                        ProxyUiStateError::MissingTarget {
+                            proxy_ui_entity,
+                            target_entity: proxy_target_entity,
+                        }
+                    })
+                },
+            );
+        if let Err(err) = validation {
+            warn!("{err}, removing world proxy");
+            commands.entity(proxy_ui_entity).remove::<WorldProxyUi>();
+            continue;
+        }
+
+        commands
+            .entity(proxy_target_entity)
+            .insert(ProxyUiProxied(proxy_ui_entity));
+        commands
+            .entity(proxy_ui_entity)
+            .insert(ProxyUiTarget(proxy_target_entity));
+        proxy_ui_entity_map.insert(proxy_target_entity, proxy_ui_entity);
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn world_proxy_ui_update(
+    mut commands: Commands,
+    mut events: EventWriter<OffscreenIndicatorEvent>,
+    coords: ProxyUiCoords,
+    mut world_proxy_ui_query: Query<
+        (
+            Entity,
+            &WorldProxyUi,
+            &ProxyUiTarget,
+            &Node,
+            &mut Style,
+            Option<&mut OffscreenIndicator>,
+            Option<&ProxyUiPriority>,
+            Option<&ProxyUiDeclutterHidden>,
+            Option<&Visibility>,
+        ),
+        Without<ProxyUiPaused>,
+    >,
+    proxy_target_query: Query<&GlobalTransform, With<ProxyUiProxied>>,
+    declutter: Option<Res<ProxyUiDeclutter>>,
+) {
+    let mut placements = Vec::new();
+    for (
+        proxy_ui_entity,
+        world_proxy_ui,
+        proxy_ui_target,
+        node,
+        _,
+        option_indicator,
+        option_priority,
+        ..,
+    ) in world_proxy_ui_query.iter_mut()
+    {
+        let Ok(target_global_transform) = proxy_target_query.get(proxy_ui_target.target_entity())
+        else {
+            let err = ProxyUiStateError::MissingTarget {
+                proxy_ui_entity,
+                target_entity: proxy_ui_target.target_entity(),
+            };
+            warn!("{err}, removing world proxy");
+            commands.unlink_proxy_ui(proxy_ui_entity);
+            commands.entity(proxy_ui_entity).remove::<WorldProxyUi>();
+            continue;
+        };
+
+        let ui_rect = match world_proxy_ui.bounds {
+            Some(bounds) => {
+                let corners = [
+                    bounds.min,
+                    Vec2::new(bounds.max.x, bounds.min.y),
+                    bounds.max,
+                    Vec2::new(bounds.min.x, bounds.max.y),
+                ]
+                .map(|corner| {
+                    coords.world_to_ui(target_global_transform.transform_point(corner.extend(0.0)))
+                });
+                corners
+                    .into_iter()
+                    .collect::<Option<Vec<_>>>()
+                    .map(|corners| {
+                        corners.iter().fold(
+                            Rect::from_center_size(corners[0], Vec2::ZERO),
+                            |rect, corner| rect.union_point(*corner),
+                        )
+                    })
+            }
+            None => coords
+                .world_to_ui(target_global_transform.translation())
+                .map(|ui_position| Rect::from_center_size(ui_position, node.size())),
+        };
+        let ui_rect = match ui_rect {
+            Some(ui_rect) => ui_rect,
+            // targets behind the camera can't be followed, but indicators still point at them
+            None if option_indicator.is_some() => {
+                let Some(ui_position) =
+                    coords.world_to_ui_unbounded(target_global_transform.translation())
+                else {
+                    continue;
+                };
+                Rect::from_center_size(ui_position, node.size())
+            }
+            None => continue,
+        };
+
+        let ui_rect = Rect {
+            min: ui_rect.min + world_proxy_ui.offset,
+            max: ui_rect.max + world_proxy_ui.offset,
+        };
+        let ui_rect = match (option_indicator, coords.ui_viewport_rect()) {
+            (Some(mut indicator), Some(viewport_rect)) => {
+                let (position, angle, on_screen) = indicator.clamp(viewport_rect, ui_rect.center());
+                // the first clamp only initializes the state
+                if indicator
+                    .on_screen
+                    .is_some_and(|was_on_screen| was_on_screen != on_screen)
+                {
+                    events.send(OffscreenIndicatorEvent {
+                        proxy_ui_entity,
+                        target_entity: proxy_ui_target.target_entity(),
+                        on_screen,
+                    });
+                }
+                indicator.set_if_neq(OffscreenIndicator {
+                    on_screen: Some(on_screen),
+                    angle,
+                    ..*indicator
+                });
+                Rect::from_center_size(position, ui_rect.size())
+            }
+            _ => ui_rect,
+        };
+        let priority = option_priority.copied().unwrap_or_default();
+        placements.push((proxy_ui_entity, ui_rect, priority));
+    }
+
+    // labels that stay in place come first
+    placements.sort_by_key(|(entity, _, priority)| (Reverse(*priority), *entity));
+    let resolved = match declutter {
+        Some(declutter) => declutter.resolve(
+            &placements
+                .iter()
+                .map(|(_, ui_rect, priority)| (*ui_rect, priority.0))
+                .collect::<Vec<_>>(),
+        ),
+        None => vec![(Vec2::ZERO, false); placements.len()],
+    };
+
+    for ((proxy_ui_entity, ui_rect, _), (offset, hidden)) in placements.into_iter().zip(resolved) {
+        let Ok((_, world_proxy_ui, _, _, mut style, _, _, option_hidden, option_visibility)) =
+            world_proxy_ui_query.get_mut(proxy_ui_entity)
+        else {
+            continue;
+        };
+        let mut new_style = Style {
+            position_type: PositionType::Absolute,
+            left: Val::Px(ui_rect.min.x + offset.x),
+            top: Val::Px(ui_rect.min.y + offset.y),
+            ..style.clone()
+        };
+        if world_proxy_ui.bounds.is_some() {
+            new_style.width = Val::Px(ui_rect.width());
+            new_style.height = Val::Px(ui_rect.height());
+        }
+        // avoid triggering a layout every frame when nothing moved
+        style.set_if_neq(new_style);
+
+        match (hidden, option_hidden) {
+            (true, None) => {
+                let visibility = option_visibility.copied().unwrap_or_default();
+                commands
+                    .entity(proxy_ui_entity)
+                    .insert((Visibility::Hidden, ProxyUiDeclutterHidden(visibility)));
+            }
+            (false, Some(ProxyUiDeclutterHidden(visibility))) => {
+                commands
+                    .entity(proxy_ui_entity)
+                    .insert(*visibility)
+                    .remove::<ProxyUiDeclutterHidden>();
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::window::PrimaryWindow;
+
+    use crate::test::lib::*;
+
+    use super::*;
+
+    #[test]
+    fn test_world_proxy_ui() {
+        let mut app = setup_test_app();
+        app.add_event::<OffscreenIndicatorEvent>();
+        spawn_window_camera(&mut app);
+        let target_entity = app
+            .world
+            .spawn(GlobalTransform::from_xyz(100.0, 50.0, 0.0))
+            .id();
+        let proxy_ui_entity = app
+            .world
+            .spawn((
+                WorldProxyUi::follow(target_entity)
+                    .with_bounds(Rect::new(-10.0, -10.0, 10.0, 10.0)),
+                NodeBundle::default(),
+            ))
+            .id();
+        app.world.run_system_once(world_proxy_ui_added);
+        app.world.run_system_once(world_proxy_ui_update);
+
+        assert!(app
+            .world
+            .resource::<ProxyUiEntityMap>()
+            .contains_proxy(&proxy_ui_entity));
+        let style = app.world.get::<Style>(proxy_ui_entity).unwrap();
+        assert_eq!(style.position_type, PositionType::Absolute);
+        assert_eq!(style.left, Val::Px(730.0));
+        assert_eq!(style.top, Val::Px(300.0));
+        assert_eq!(style.width, Val::Px(20.0));
+        assert_eq!(style.height, Val::Px(20.0));
+    }
+
+    #[test]
+    fn test_world_proxy_ui_declutter() {
+        let mut app = setup_test_app();
+        app.add_event::<OffscreenIndicatorEvent>();
+        app.insert_resource(ProxyUiDeclutter {
+            max_displacement: 1.0,
+            padding: 0.0,
+            hide_low_priority: true,
+            ..default()
+        });
+        spawn_window_camera(&mut app);
+        let bounds = Rect::new(-20.0, -5.0, 20.0, 5.0);
+        let high_target_entity = app.world.spawn(GlobalTransform::IDENTITY).id();
+        let high_entity = app
+            .world
+            .spawn((
+                WorldProxyUi::follow(high_target_entity).with_bounds(bounds),
+                ProxyUiPriority(1),
+                NodeBundle::default(),
+            ))
+            .id();
+        // overlaps the lower half of the high priority label
+        let low_target_entity = app
+            .world
+            .spawn(GlobalTransform::from_xyz(0.0, -5.0, 0.0))
+            .id();
+        let low_entity = app
+            .world
+            .spawn((
+                WorldProxyUi::follow(low_target_entity).with_bounds(bounds),
+                NodeBundle {
+                    visibility: Visibility::Visible,
+                    ..default()
+                },
+            ))
+            .id();
+        app.world.run_system_once(world_proxy_ui_added);
+
+        let position = |app: &App, entity: Entity| {
+            let style = app.world.get::<Style>(entity).unwrap();
+            (style.left, style.top)
+        };
+        app.world.run_system_once(world_proxy_ui_update);
+        assert_eq!(
+            position(&app, high_entity),
+            (Val::Px(620.0), Val::Px(355.0))
+        );
+        assert_eq!(position(&app, low_entity), (Val::Px(620.0), Val::Px(361.0)));
+        assert_eq!(
+            app.world.get::<Visibility>(low_entity),
+            Some(&Visibility::Hidden)
+        );
+
+        // nothing moved, so nothing changes
+        app.world.run_system_once(world_proxy_ui_update);
+        assert_eq!(
+            position(&app, high_entity),
+            (Val::Px(620.0), Val::Px(355.0))
+        );
+        assert_eq!(position(&app, low_entity), (Val::Px(620.0), Val::Px(361.0)));
+        assert_eq!(
+            app.world.get::<Visibility>(low_entity),
+            Some(&Visibility::Hidden)
+        );
+
+        // moved apart, the label's own visibility is restored
+        *app.world
+            .get_mut::<GlobalTransform>(low_target_entity)
+            .unwrap() = GlobalTransform::from_xyz(200.0, 0.0, 0.0);
+        app.world.run_system_once(world_proxy_ui_update);
+        assert_eq!(position(&app, low_entity), (Val::Px(820.0), Val::Px(355.0)));
+        assert_eq!(
+            app.world.get::<Visibility>(low_entity),
+            Some(&Visibility::Visible)
+        );
+        assert!(app
+            .world
+            .get::<ProxyUiDeclutterHidden>(low_entity)
+            .is_none());
+    }
+
+    fn drain_indicator_events(app: &mut App) -> Vec<bool> {
+        app.world
+            .resource_mut::<Events<OffscreenIndicatorEvent>>()
+            .drain()
+            .map(|event| event.on_screen)
+            .collect()
+    }
+
+    #[test]
+    fn test_offscreen_indicator() {
+        let mut app = setup_test_app();
+        app.add_event::<OffscreenIndicatorEvent>();
+        spawn_window_camera(&mut app);
+        let target_entity = app.world.spawn(GlobalTransform::IDENTITY).id();
+        let proxy_ui_entity = app
+            .world
+            .spawn((
+                WorldProxyUi::follow(target_entity),
+                OffscreenIndicator::new(10.0),
+                NodeBundle::default(),
+            ))
+            .id();
+        app.world.run_system_once(world_proxy_ui_added);
+
+        // the initial state doesn't send an event
+        app.world.run_system_once(world_proxy_ui_update);
+        assert!(drain_indicator_events(&mut app).is_empty());
+        let indicator = app
+            .world
+            .get::<OffscreenIndicator>(proxy_ui_entity)
+            .unwrap();
+        assert!(indicator.is_on_screen());
+
+        // off screen to the right
+        *app.world.get_mut::<GlobalTransform>(target_entity).unwrap() =
+            GlobalTransform::from_xyz(1000.0, 0.0, 0.0);
+        app.world.run_system_once(world_proxy_ui_update);
+        assert_eq!(drain_indicator_events(&mut app), vec![false]);
+        let indicator = app
+            .world
+            .get::<OffscreenIndicator>(proxy_ui_entity)
+            .unwrap();
+        assert!(!indicator.is_on_screen());
+        assert_eq!(indicator.get_angle(), 0.0);
+        let style = app.world.get::<Style>(proxy_ui_entity).unwrap();
+        assert_eq!(style.left, Val::Px(1270.0));
+        assert_eq!(style.top, Val::Px(360.0));
+
+        // staying off screen doesn't send another event
+        app.world.run_system_once(world_proxy_ui_update);
+        assert!(drain_indicator_events(&mut app).is_empty());
+
+        // back on screen
+        *app.world.get_mut::<GlobalTransform>(target_entity).unwrap() =
+            GlobalTransform::from_xyz(100.0, 50.0, 0.0);
+        app.world.run_system_once(world_proxy_ui_update);
+        assert_eq!(drain_indicator_events(&mut app), vec![true]);
+        let style = app.world.get::<Style>(proxy_ui_entity).unwrap();
+        assert_eq!(style.left, Val::Px(740.0));
+        assert_eq!(style.top, Val::Px(310.0));
+    }
+
+    #[test]
+    fn test_offscreen_indicator_behind_camera() {
+        let mut app = setup_test_app();
+        app.add_event::<OffscreenIndicatorEvent>();
+        app.world.spawn((Window::default(), PrimaryWindow));
+        app.world.spawn(Camera3dBundle {
+            transform: Transform::from_xyz(0.0, 0.0, 500.0),
+            projection: Projection::Perspective(PerspectiveProjection {
+                aspect_ratio: 1280.0 / 720.0,
+                ..default()
+            }),
+            ..default()
+        });
+        // behind and to the right of the camera
+        let target_entity = app
+            .world
+            .spawn(GlobalTransform::from_xyz(100.0, 0.0, 1000.0))
+            .id();
+        let proxy_ui_entity = app
+            .world
+            .spawn((
+                WorldProxyUi::follow(target_entity),
+                OffscreenIndicator::new(10.0),
+                NodeBundle::default(),
+            ))
+            .id();
+        app.world.run_system_once(world_proxy_ui_added);
+        app.world.run_system_once(world_proxy_ui_update);
+
+        let indicator = app
+            .world
+            .get::<OffscreenIndicator>(proxy_ui_entity)
+            .unwrap();
+        assert!(!indicator.is_on_screen());
+        let style = app.world.get::<Style>(proxy_ui_entity).unwrap();
+        assert_eq!(style.left, Val::Px(1270.0));
+        assert_eq!(style.top, Val::Px(360.0));
+    }
+}
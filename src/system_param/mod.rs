@@ -0,0 +1,8 @@
+pub use proxy_ui_coords::*;
+pub use proxy_ui_cursor::*;
+pub use proxy_ui_links::*;
+
+pub(crate) mod proxy_ui_cameras;
+pub mod proxy_ui_coords;
+pub mod proxy_ui_cursor;
+pub mod proxy_ui_links;
@@ -0,0 +1,79 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::ui::DefaultUiCamera;
+use bevy::window::PrimaryWindow;
+
+use crate::prelude::*;
+use crate::util::ui::{camera_logical_viewport_rect, is_window_camera};
+
+/// The camera rendering a ui node, ui positions are relative to its viewport
+pub(crate) struct ProxyUiViewCamera<'a> {
+    pub(crate) entity: Entity,
+    pub(crate) transform: &'a Transform,
+    pub(crate) projection: Projection,
+    /// the viewport in logical window pixels
+    pub(crate) viewport_rect: Rect,
+}
+
+/// Resolves the camera rendering a ui node the same way bevy_ui does,
+/// through the `TargetCamera` of the node's root or the `DefaultUiCamera`
+#[allow(clippy::type_complexity)]
+#[derive(SystemParam)]
+pub(crate) struct ProxyUiCameras<'w, 's> {
+    default_ui_camera: DefaultUiCamera<'w, 's>,
+    primary_window_query: Query<'w, 's, &'static Window, With<PrimaryWindow>>,
+    node_query: Query<'w, 's, (Option<&'static TargetCamera>, Option<&'static Parent>), With<Node>>,
+    camera_query: Query<
+        'w,
+        's,
+        (
+            &'static Camera,
+            &'static Transform,
+            AnyOf<(&'static OrthographicProjection, &'static Projection)>,
+        ),
+        // proxied cameras render into their proxy node
+        Without<ProxyUiProxied>,
+    >,
+}
+
+impl<'w, 's> ProxyUiCameras<'w, 's> {
+    /// returns the camera rendering the node, `TargetCamera` is only read from root nodes by
+    /// bevy_ui so the closest one up the hierarchy is used
+    pub(crate) fn node_camera(&self, node_entity: Entity) -> Option<ProxyUiViewCamera<'_>> {
+        let mut entity = node_entity;
+        while let Ok((option_target_camera, option_parent)) = self.node_query.get(entity) {
+            if let Some(target_camera) = option_target_camera {
+                return self.camera(target_camera.entity());
+            }
+            let Some(parent) = option_parent else {
+                break;
+            };
+            entity = parent.get();
+        }
+        self.default_camera()
+    }
+    /// returns the camera rendering nodes without a `TargetCamera`
+    pub(crate) fn default_camera(&self) -> Option<ProxyUiViewCamera<'_>> {
+        self.camera(self.default_ui_camera.get()?)
+    }
+    fn camera(&self, camera_entity: Entity) -> Option<ProxyUiViewCamera<'_>> {
+        let window = self.primary_window_query.get_single().ok()?;
+        let (camera, transform, (orthographic, projection)) =
+            self.camera_query.get(camera_entity).ok()?;
+        if !is_window_camera(camera) {
+            return None;
+        }
+        // 2d cameras use OrthographicProjection directly, 3d cameras the Projection enum
+        let projection = match (orthographic, projection) {
+            (Some(orthographic), _) => Projection::Orthographic(orthographic.clone()),
+            (_, Some(projection)) => projection.clone(),
+            _ => return None,
+        };
+        Some(ProxyUiViewCamera {
+            entity: camera_entity,
+            transform,
+            projection,
+            viewport_rect: camera_logical_viewport_rect(camera, window),
+        })
+    }
+}
@@ -0,0 +1,196 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use crate::system_param::proxy_ui_cameras::{ProxyUiCameras, ProxyUiViewCamera};
+use crate::util::ui::{ui_to_world, world_to_ui, world_to_ui_unbounded};
+
+/// Converts between ui space and world space through the default ui camera, the camera
+/// `ProxyUiNodeState` uses for nodes without a `TargetCamera`, with `UiScale` and the camera
+/// viewport resolved.
+///
+/// Perspective cameras convert ui positions to the z = 0 plane.
+/// Every conversion returns `None` without a primary window or an active camera
+#[derive(SystemParam)]
+pub struct ProxyUiCoords<'w, 's> {
+    ui_scale: Option<Res<'w, UiScale>>,
+    cameras: ProxyUiCameras<'w, 's>,
+}
+
+impl<'w, 's> ProxyUiCoords<'w, 's> {
+    /// converts a position in ui space to world space
+    pub fn ui_to_world(&self, ui_position: Vec2) -> Option<Vec2> {
+        let ui_camera = self.ui_camera()?;
+        Some(ui_to_world(
+            ui_position * self.ui_scale(),
+            ui_camera.viewport_rect.size(),
+            ui_camera.transform,
+            &ui_camera.projection,
+        ))
+    }
+    /// converts a position in world space to ui space,
+    /// returns `None` for positions behind the camera
+    pub fn world_to_ui(&self, world_position: Vec3) -> Option<Vec2> {
+        let ui_camera = self.ui_camera()?;
+        world_to_ui(
+            world_position,
+            ui_camera.viewport_rect.size(),
+            ui_camera.transform,
+            &ui_camera.projection,
+        )
+        .map(|logical_position| logical_position / self.ui_scale())
+    }
+    /// converts a position in world space to ui space like `world_to_ui`,
+    /// but positions behind the camera are placed far outside of the viewport on their side,
+    /// useful for pointing at off screen targets
+    pub fn world_to_ui_unbounded(&self, world_position: Vec3) -> Option<Vec2> {
+        let ui_camera = self.ui_camera()?;
+        let logical_position = world_to_ui_unbounded(
+            world_position,
+            ui_camera.viewport_rect.size(),
+            ui_camera.transform,
+            &ui_camera.projection,
+        );
+        Some(logical_position / self.ui_scale())
+    }
+    /// converts a rect in ui space to world space
+    pub fn ui_rect_to_world(&self, ui_rect: Rect) -> Option<Rect> {
+        Some(Rect::from_corners(
+            self.ui_to_world(ui_rect.min)?,
+            self.ui_to_world(ui_rect.max)?,
+        ))
+    }
+    /// converts a rect on the z = 0 plane in world space to ui space
+    pub fn world_rect_to_ui(&self, world_rect: Rect) -> Option<Rect> {
+        Some(Rect::from_corners(
+            self.world_to_ui(world_rect.min.extend(0.0))?,
+            self.world_to_ui(world_rect.max.extend(0.0))?,
+        ))
+    }
+    /// returns the viewport of the camera in ui space,
+    /// ui positions are relative to the viewport so it always starts at zero
+    pub fn ui_viewport_rect(&self) -> Option<Rect> {
+        let ui_camera = self.ui_camera()?;
+        Some(Rect::from_corners(
+            Vec2::ZERO,
+            ui_camera.viewport_rect.size() / self.ui_scale(),
+        ))
+    }
+    fn ui_scale(&self) -> f32 {
+        self.ui_scale
+            .as_ref()
+            .map(|ui_scale| ui_scale.0)
+            .unwrap_or(1.0)
+    }
+    fn ui_camera(&self) -> Option<ProxyUiViewCamera<'_>> {
+        self.cameras.default_camera()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::window::PrimaryWindow;
+
+    use crate::prelude::*;
+    use crate::system::proxy_ui_added::proxy_ui_added;
+    use crate::system::proxy_ui_update::proxy_ui_update;
+    use crate::test::lib::*;
+
+    use super::*;
+
+    fn assert_round_trip(mut app: App) {
+        app.world.run_system_once(|coords: ProxyUiCoords| {
+            for ui_position in [Vec2::new(200.0, 100.0), Vec2::new(640.0, 360.0)] {
+                let world_position = coords.ui_to_world(ui_position).unwrap();
+                let round_trip = coords.world_to_ui(world_position.extend(0.0)).unwrap();
+                assert!(round_trip.abs_diff_eq(ui_position, 1e-2));
+            }
+            let ui_rect = Rect::new(100.0, 100.0, 300.0, 200.0);
+            let world_rect = coords.ui_rect_to_world(ui_rect).unwrap();
+            let round_trip = coords.world_rect_to_ui(world_rect).unwrap();
+            assert!(round_trip.min.abs_diff_eq(ui_rect.min, 1e-2));
+            assert!(round_trip.max.abs_diff_eq(ui_rect.max, 1e-2));
+        });
+    }
+
+    fn setup_window_app() -> App {
+        let mut app = setup_test_app();
+        app.insert_resource(UiScale(2.0));
+        app.world.spawn((Window::default(), PrimaryWindow));
+        app
+    }
+
+    #[test]
+    fn test_proxy_ui_coords_orthographic() {
+        let mut app = setup_test_app();
+        app.insert_resource(UiScale(2.0));
+        spawn_window_camera(&mut app);
+        // the center of the window is the camera position
+        app.world.run_system_once(|coords: ProxyUiCoords| {
+            assert_eq!(
+                coords.ui_to_world(Vec2::new(320.0, 180.0)),
+                Some(Vec2::ZERO)
+            );
+        });
+        assert_round_trip(app);
+    }
+
+    #[test]
+    fn test_proxy_ui_coords_perspective() {
+        let mut app = setup_window_app();
+        app.world.spawn(Camera3dBundle {
+            transform: Transform::from_xyz(10.0, 20.0, 500.0),
+            projection: Projection::Perspective(PerspectiveProjection {
+                aspect_ratio: 1280.0 / 720.0,
+                ..default()
+            }),
+            ..default()
+        });
+        assert_round_trip(app);
+    }
+
+    #[test]
+    fn test_proxy_ui_coords_match_node_state() {
+        let mut app = setup_window_app();
+        // the default ui camera is the perspective camera with the highest order
+        app.world.spawn(Camera2dBundle::default());
+        app.world.spawn(Camera3dBundle {
+            camera: Camera {
+                order: 1,
+                ..default()
+            },
+            transform: Transform::from_xyz(10.0, 20.0, 500.0),
+            projection: Projection::Perspective(PerspectiveProjection {
+                aspect_ratio: 1280.0 / 720.0,
+                ..default()
+            }),
+            ..default()
+        });
+        let proxied_target_entity = app.world.spawn(DummyComponent).id();
+        app.world.spawn((
+            ProxyUi::proxy(proxied_target_entity),
+            NodeBundle {
+                node: sized_node(Vec2::new(100.0, 50.0)),
+                global_transform: GlobalTransform::from_xyz(100.0, 100.0, 0.0),
+                ..default()
+            },
+        ));
+        app.world.run_system_once(proxy_ui_added);
+        app.world.run_system_once(proxy_ui_update);
+
+        let node_state = app
+            .world
+            .get::<ProxyUiNodeState>(proxied_target_entity)
+            .unwrap()
+            .clone();
+        app.world.run_system_once(move |coords: ProxyUiCoords| {
+            let world_rect = coords.ui_rect_to_world(*node_state.get_ui_rect()).unwrap();
+            assert!(world_rect
+                .min
+                .abs_diff_eq(node_state.get_world_rect().min, 1e-3));
+            assert!(world_rect
+                .max
+                .abs_diff_eq(node_state.get_world_rect().max, 1e-3));
+        });
+    }
+}
@@ -0,0 +1,197 @@
+use bevy::ecs::system::SystemParam;
+use bevy::input::touch::Touches;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use crate::prelude::*;
+use crate::system_param::proxy_ui_cameras::ProxyUiCameras;
+use crate::util::ui::ui_point_in_node;
+
+/// The position of the cursor or a touch over a proxy ui node
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProxyUiCursorPosition {
+    pub proxy_ui_entity: Entity,
+    pub target_entity: Entity,
+    /// the touch id, `None` for the mouse cursor
+    pub touch_id: Option<u64>,
+    /// position in ui space, relative to the viewport of the camera rendering the node
+    pub ui: Vec2,
+    /// position in the unrotated node rect, 0..1 from the top left corner
+    pub normalized: Vec2,
+    /// position in world space, relative to the camera for `ProxyUiSpace::CameraLocal`
+    pub world: Vec2,
+    /// position in the local space of the proxied target, with the applied transform inverted
+    pub local: Vec2,
+}
+
+/// Finds the proxy ui nodes under the cursor and touches
+#[derive(SystemParam)]
+pub struct ProxyUiCursor<'w, 's> {
+    ui_scale: Option<Res<'w, UiScale>>,
+    touches: Option<Res<'w, Touches>>,
+    primary_window_query: Query<'w, 's, &'static Window, With<PrimaryWindow>>,
+    proxy_ui_query: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static ProxyUiTarget,
+            &'static ProxyUiNodeState,
+            &'static GlobalTransform,
+        ),
+    >,
+    proxy_target_query:
+        Query<'w, 's, (&'static Transform, &'static GlobalTransform), With<ProxyUiProxied>>,
+    cameras: ProxyUiCameras<'w, 's>,
+}
+
+impl<'w, 's> ProxyUiCursor<'w, 's> {
+    /// returns the positions over every proxy ui node under the cursor and any touch
+    pub fn positions(&self) -> Vec<ProxyUiCursorPosition> {
+        let ui_scale = self
+            .ui_scale
+            .as_ref()
+            .map(|ui_scale| ui_scale.0)
+            .unwrap_or(1.0);
+        let cursor = self
+            .primary_window_query
+            .get_single()
+            .ok()
+            .and_then(|window| window.cursor_position())
+            .map(|position| (None, position));
+        let touches = self
+            .touches
+            .iter()
+            .flat_map(|touches| touches.iter())
+            .map(|touch| (Some(touch.id()), touch.position()));
+        cursor
+            .into_iter()
+            .chain(touches)
+            .flat_map(|(touch_id, logical_position)| {
+                self.proxy_ui_query.iter().filter_map(
+                    move |(proxy_ui_entity, proxy_ui_target, node_state, global_transform)| {
+                        // node positions are relative to the viewport of their camera
+                        let viewport_rect =
+                            self.cameras.node_camera(proxy_ui_entity)?.viewport_rect;
+                        let ui_position = (logical_position - viewport_rect.min) / ui_scale;
+                        self.locate(
+                            proxy_ui_entity,
+                            proxy_ui_target.target_entity(),
+                            node_state,
+                            global_transform,
+                            touch_id,
+                            ui_position,
+                        )
+                    },
+                )
+            })
+            .collect()
+    }
+    /// returns the cursor position over the proxy ui node linked to `target_entity`
+    pub fn position_over(&self, target_entity: Entity) -> Option<ProxyUiCursorPosition> {
+        self.positions()
+            .into_iter()
+            .find(|position| position.target_entity == target_entity && position.touch_id.is_none())
+    }
+    fn locate(
+        &self,
+        proxy_ui_entity: Entity,
+        target_entity: Entity,
+        node_state: &ProxyUiNodeState,
+        global_transform: &GlobalTransform,
+        touch_id: Option<u64>,
+        ui: Vec2,
+    ) -> Option<ProxyUiCursorPosition> {
+        // the world quad is built from the snapped rect when pixel snapping is enabled
+        let ui_rect = node_state.get_snapped_ui_rect();
+        let node_position = ui_point_in_node(ui, global_transform);
+        if !ui_rect.contains(node_position) || ui_rect.is_empty() {
+            return None;
+        }
+        let normalized = (node_position - ui_rect.min) / ui_rect.size();
+        let world = node_state.get_world_quad().point_at(normalized);
+        let (target_transform, target_global_transform) =
+            self.proxy_target_query.get(target_entity).ok()?;
+        // camera local world positions are relative to the camera, the parent of the target
+        let target_affine = match node_state.get_space() {
+            ProxyUiSpace::World => target_global_transform.affine(),
+            ProxyUiSpace::CameraLocal => target_transform.compute_affine(),
+        };
+        let local = target_affine
+            .inverse()
+            .transform_point3(world.extend(target_affine.translation.z))
+            .truncate();
+        Some(ProxyUiCursorPosition {
+            proxy_ui_entity,
+            target_entity,
+            touch_id,
+            ui,
+            normalized,
+            world,
+            local,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use crate::system::proxy_ui_added::proxy_ui_added;
+    use crate::system::proxy_ui_update::proxy_ui_update;
+    use crate::test::lib::*;
+
+    use super::*;
+
+    fn set_cursor_position(app: &mut App, position: Vec2) {
+        app.world
+            .query::<&mut Window>()
+            .single_mut(&mut app.world)
+            .set_cursor_position(Some(position));
+    }
+
+    #[test]
+    fn test_proxy_ui_cursor() {
+        let mut app = setup_test_app();
+        spawn_window_camera(&mut app);
+        set_cursor_position(&mut app, Vec2::new(600.0, 320.0));
+        let target_entity = app
+            .world
+            .spawn((Transform::default(), GlobalTransform::default()))
+            .id();
+        // the ui rect (590.25, 310.25) (690.25, 410.25) snaps to (590, 310) (690, 410)
+        let proxy_ui_entity = app
+            .world
+            .spawn((
+                ProxyUi::proxy(target_entity),
+                ProxyUiPixelSnap {
+                    snap_to_pixels: true,
+                    ..default()
+                },
+                NodeBundle {
+                    node: sized_node(Vec2::splat(100.0)),
+                    global_transform: GlobalTransform::from_xyz(640.25, 360.25, 0.0),
+                    ..default()
+                },
+            ))
+            .id();
+        app.world.run_system_once(proxy_ui_added);
+        app.world.run_system_once(proxy_ui_update);
+
+        app.world.run_system_once(move |cursor: ProxyUiCursor| {
+            let position = cursor.position_over(target_entity).unwrap();
+            assert_eq!(position.proxy_ui_entity, proxy_ui_entity);
+            assert_eq!(position.touch_id, None);
+            assert_eq!(position.ui, Vec2::new(600.0, 320.0));
+            assert!(position.normalized.abs_diff_eq(Vec2::new(0.1, 0.1), 1e-4));
+            assert!(position.world.abs_diff_eq(Vec2::new(-40.0, 40.0), 1e-3));
+            assert!(position.local.abs_diff_eq(position.world, 1e-3));
+        });
+
+        // outside of the node
+        set_cursor_position(&mut app, Vec2::new(100.0, 100.0));
+        app.world.run_system_once(move |cursor: ProxyUiCursor| {
+            assert!(cursor.positions().is_empty());
+        });
+    }
+}
@@ -0,0 +1,87 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use crate::prelude::*;
+use crate::resource::proxy_ui_entity_map::ProxyUiEntityMap;
+
+/// Read-only lookups between proxy ui nodes and their proxied targets
+#[derive(SystemParam)]
+pub struct ProxyUiLinks<'w, 's> {
+    proxy_ui_entity_map: Res<'w, ProxyUiEntityMap>,
+    node_state_query: Query<'w, 's, &'static ProxyUiNodeState>,
+}
+
+impl<'w, 's> ProxyUiLinks<'w, 's> {
+    /// returns the proxy ui node of the proxied target
+    pub fn proxy_of(&self, proxied_target_entity: Entity) -> Option<Entity> {
+        self.proxy_ui_entity_map
+            .get_proxy_entity(&proxied_target_entity)
+            .copied()
+    }
+    /// returns the proxied target of the proxy ui node
+    pub fn target_of(&self, proxy_ui_entity: Entity) -> Option<Entity> {
+        self.proxy_ui_entity_map
+            .get_proxied_target_entity(&proxy_ui_entity)
+            .copied()
+    }
+    /// iterates over all links as `(proxied_target_entity, proxy_ui_entity)`
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, Entity)> + '_ {
+        self.proxy_ui_entity_map.iter()
+    }
+    /// returns true if the entity is the proxied target of a link
+    pub fn is_proxied(&self, proxied_target_entity: Entity) -> bool {
+        self.proxy_ui_entity_map.is_proxied(&proxied_target_entity)
+    }
+    /// returns true if the entity is the proxy ui node of a link
+    pub fn is_proxy(&self, proxy_ui_entity: Entity) -> bool {
+        self.proxy_ui_entity_map.contains_proxy(&proxy_ui_entity)
+    }
+    /// returns the node state of either side of a link
+    pub fn state_of(&self, entity: Entity) -> Option<&ProxyUiNodeState> {
+        self.node_state_query.get(entity).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use crate::system::proxy_ui_added::proxy_ui_added;
+    use crate::system::proxy_ui_update::proxy_ui_update;
+    use crate::test::lib::*;
+
+    use super::*;
+
+    #[test]
+    fn test_proxy_ui_links() {
+        let mut app = setup_test_app();
+        let proxied_target_entity = app.world.spawn(DummyComponent).id();
+        let proxy_ui_entity = app.world.spawn(ProxyUi::proxy(proxied_target_entity)).id();
+        let unlinked_entity = app.world.spawn(DummyComponent).id();
+        app.world.run_system_once(proxy_ui_added);
+        app.world.run_system_once(proxy_ui_update);
+
+        app.world.run_system_once(move |links: ProxyUiLinks| {
+            assert_eq!(links.proxy_of(proxied_target_entity), Some(proxy_ui_entity));
+            assert_eq!(
+                links.target_of(proxy_ui_entity),
+                Some(proxied_target_entity)
+            );
+            assert_eq!(links.proxy_of(unlinked_entity), None);
+            assert_eq!(links.target_of(unlinked_entity), None);
+            assert_eq!(
+                links.iter().collect::<Vec<_>>(),
+                vec![(proxied_target_entity, proxy_ui_entity)]
+            );
+            assert!(links.is_proxied(proxied_target_entity));
+            assert!(!links.is_proxied(proxy_ui_entity));
+            assert!(links.is_proxy(proxy_ui_entity));
+            assert!(links.state_of(proxied_target_entity).is_some());
+            assert_eq!(
+                links.state_of(proxied_target_entity),
+                links.state_of(proxy_ui_entity)
+            );
+            assert!(links.state_of(unlinked_entity).is_none());
+        });
+    }
+}
@@ -2,6 +2,9 @@
 #[cfg(test)]
 pub(crate) mod lib {
     use bevy::prelude::*;
+    use bevy::reflect::GetField;
+    use bevy::render::camera::CameraProjection;
+    use bevy::window::PrimaryWindow;
 
     use crate::prelude::*;
     use crate::resource::proxy_ui_entity_map::ProxyUiEntityMap;
@@ -9,13 +12,35 @@ pub(crate) mod lib {
     pub(crate) fn setup_test_app() -> App {
         let mut app = App::new();
         app.add_plugins(MinimalPlugins)
-            .init_resource::<ProxyUiEntityMap>();
+            .init_resource::<ProxyUiEntityMap>()
+            .init_resource::<ProxyUiErrorPolicy>();
         app
     }
 
     #[derive(Debug, Component)]
     pub(crate) struct DummyComponent;
 
+    /// returns a `Node` with the given size, the layout systems that compute it don't run in tests
+    pub(crate) fn sized_node(size: Vec2) -> Node {
+        let mut node = Node::default();
+        *node.get_field_mut::<Vec2>("calculated_size").unwrap() = size;
+        node
+    }
+
+    /// spawns a 1280x720 primary window and a 2d camera rendering to it,
+    /// returns the camera entity
+    pub(crate) fn spawn_window_camera(app: &mut App) -> Entity {
+        app.world.spawn((Window::default(), PrimaryWindow));
+        let mut projection = OrthographicProjection::default();
+        projection.update(1280.0, 720.0);
+        app.world
+            .spawn(Camera2dBundle {
+                projection,
+                ..default()
+            })
+            .id()
+    }
+
     pub(crate) fn set_scene(mut commands: Commands) {
         let dummy_proxied_entity = commands.spawn(DummyComponent).id();
         let dummy_proxy_ui_entity = commands.spawn(DummyComponent).id();
@@ -1 +1,2 @@
+pub(crate) mod style;
 pub(crate) mod ui;
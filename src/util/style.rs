@@ -0,0 +1,59 @@
+use bevy::math::{Rect, Vec2};
+use bevy::prelude::{UiRect, Val};
+
+/// resolves a `Val` to ui units
+///
+/// percentages resolve against the width of the parent like css padding and border do
+pub(crate) fn resolve_val(val: Val, parent_width: f32, viewport_size: Vec2) -> f32 {
+    match val {
+        Val::Auto => 0.0,
+        Val::Px(value) => value,
+        Val::Percent(value) => parent_width * value / 100.0,
+        Val::Vw(value) => viewport_size.x * value / 100.0,
+        Val::Vh(value) => viewport_size.y * value / 100.0,
+        Val::VMin(value) => viewport_size.min_element() * value / 100.0,
+        Val::VMax(value) => viewport_size.max_element() * value / 100.0,
+    }
+}
+
+/// shrinks the rect by the resolved `UiRect`, never inverting it
+pub(crate) fn inset_rect(
+    rect: Rect,
+    inset: &UiRect,
+    parent_width: f32,
+    viewport_size: Vec2,
+) -> Rect {
+    let resolve = |val: Val| resolve_val(val, parent_width, viewport_size).max(0.0);
+    let min = rect.min + Vec2::new(resolve(inset.left), resolve(inset.top));
+    let max = rect.max - Vec2::new(resolve(inset.right), resolve(inset.bottom));
+    Rect {
+        min: min.min(rect.max),
+        max: max.max(min.min(rect.max)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inset_rect() {
+        let rect = Rect::new(0.0, 0.0, 100.0, 50.0);
+        let viewport_size = Vec2::new(1000.0, 500.0);
+        let inset = UiRect {
+            left: Val::Px(10.0),
+            right: Val::Percent(10.0),
+            top: Val::Vh(1.0),
+            bottom: Val::Auto,
+        };
+        assert_eq!(
+            inset_rect(rect, &inset, 200.0, viewport_size),
+            Rect::new(10.0, 5.0, 80.0, 50.0)
+        );
+        // over inset rects collapse instead of inverting
+        assert_eq!(
+            inset_rect(rect, &UiRect::all(Val::Px(60.0)), 200.0, viewport_size),
+            Rect::new(60.0, 50.0, 60.0, 50.0)
+        );
+    }
+}
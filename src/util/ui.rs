@@ -1,24 +1,297 @@
-use bevy::math::Vec2;
-use bevy::prelude::{OrthographicProjection, Transform, Window};
-use bevy::render::camera::CameraProjection;
+use bevy::math::{Rect, Vec2, Vec3, Vec4};
+use bevy::prelude::{Camera, GlobalTransform, Transform, Window};
+use bevy::render::camera::{CameraProjection, RenderTarget};
 
+/// converts a position in logical pixels relative to the camera viewport to world space,
+/// on the z = 0 plane for perspective projections
+///
+/// ui node positions are already relative to the viewport of the camera rendering them,
+/// `viewport_size` is the logical size of that viewport, see [`camera_logical_viewport_rect`]
 pub(crate) fn ui_to_world(
     pos: Vec2,
-    window: &Window,
+    viewport_size: Vec2,
     camera_transform: &Transform,
-    camera_projection: &OrthographicProjection,
+    camera_projection: &impl CameraProjection,
 ) -> Vec2 {
-    // Get the size of the window
-    let window_size = Vec2::new(window.width(), window.height());
+    // Convert cursor position to NDC (Normalized Device Coordinates) relative to the viewport
+    // ui is y down while NDC is y up
+    let ndc = (pos / viewport_size) * 2.0 - Vec2::ONE;
+    let ndc = Vec2::new(ndc.x, -ndc.y);
 
-    // Convert cursor position to NDC (Normalized Device Coordinates)
-    let ndc = (pos / window_size) * 2.0 - Vec2::ONE;
+    // Cast a ray from the near plane into the scene, depth is reversed so the near plane is at 1
+    let ndc_to_world =
+        camera_transform.compute_matrix() * camera_projection.get_projection_matrix().inverse();
+    let near = ndc_to_world.project_point3(ndc.extend(1.0));
+    let far = ndc_to_world.project_point3(ndc.extend(NDC_FAR_DEPTH));
+    let direction = far - near;
 
-    // Convert NDC to world coordinates
-    let world_pos = camera_transform.compute_matrix()
-        * camera_projection.get_projection_matrix().inverse()
-        * ndc.extend(-1.0).extend(1.0);
+    // intersect the ray with the z = 0 plane
+    if direction.z.abs() <= f32::EPSILON {
+        return near.truncate();
+    }
+    (near + direction * (-near.z / direction.z)).truncate()
+}
+
+/// a depth close to the far plane that is still finite for infinite perspective projections
+const NDC_FAR_DEPTH: f32 = 1e-6;
+
+/// converts a position in world space to logical pixels relative to the camera viewport,
+/// the inverse of [`ui_to_world`]
+///
+/// returns `None` for positions behind the camera
+pub(crate) fn world_to_ui(
+    pos: Vec3,
+    viewport_size: Vec2,
+    camera_transform: &Transform,
+    camera_projection: &impl CameraProjection,
+) -> Option<Vec2> {
+    let clip = world_to_clip(pos, camera_transform, camera_projection);
+    if clip.w <= 0.0 {
+        return None;
+    }
+    Some(ndc_to_ui(
+        clip.truncate().truncate() / clip.w,
+        viewport_size,
+    ))
+}
+
+/// like [`world_to_ui`] but positions behind the camera are placed far outside of the viewport
+/// on the side the position is on, for pointing at targets that are off screen
+pub(crate) fn world_to_ui_unbounded(
+    pos: Vec3,
+    viewport_size: Vec2,
+    camera_transform: &Transform,
+    camera_projection: &impl CameraProjection,
+) -> Vec2 {
+    let clip = world_to_clip(pos, camera_transform, camera_projection);
+    if clip.w > 0.0 {
+        return ndc_to_ui(clip.truncate().truncate() / clip.w, viewport_size);
+    }
+    // the perspective divide mirrors positions behind the camera, keep the side instead
+    // and treat positions straight behind the camera as below it
+    let direction = clip
+        .truncate()
+        .truncate()
+        .try_normalize()
+        .unwrap_or(Vec2::NEG_Y);
+    ndc_to_ui(direction * BEHIND_CAMERA_NDC_DISTANCE, viewport_size)
+}
+
+/// how far outside of the viewport positions behind the camera are placed, in NDC
+const BEHIND_CAMERA_NDC_DISTANCE: f32 = 1e3;
+
+fn world_to_clip(
+    pos: Vec3,
+    camera_transform: &Transform,
+    camera_projection: &impl CameraProjection,
+) -> Vec4 {
+    let world_to_clip =
+        camera_projection.get_projection_matrix() * camera_transform.compute_matrix().inverse();
+    world_to_clip * pos.extend(1.0)
+}
+
+fn ndc_to_ui(ndc: Vec2, viewport_size: Vec2) -> Vec2 {
+    // ui is y down while NDC is y up
+    let normalized = Vec2::new(ndc.x + 1.0, 1.0 - ndc.y) / 2.0;
+    normalized * viewport_size
+}
+
+/// returns the logical rect of the window the camera renders to,
+/// taking `Camera::viewport` into account
+pub(crate) fn camera_logical_viewport_rect(camera: &Camera, window: &Window) -> Rect {
+    // the computed viewport is only available once the camera has been updated
+    if let Some(viewport_rect) = camera.logical_viewport_rect() {
+        return viewport_rect;
+    }
+    match &camera.viewport {
+        Some(viewport) => {
+            let min = viewport.physical_position.as_vec2() / window.scale_factor();
+            let size = viewport.physical_size.as_vec2() / window.scale_factor();
+            Rect::from_corners(min, min + size)
+        }
+        None => Rect::new(0.0, 0.0, window.width(), window.height()),
+    }
+}
+
+/// returns true for active cameras rendering to a window, cameras rendering to images
+/// (like the ones created for `ProxyUiRenderTexture`) are skipped when converting ui positions
+pub(crate) fn is_window_camera(camera: &Camera) -> bool {
+    camera.is_active && matches!(camera.target, RenderTarget::Window(_))
+}
+
+/// returns the top left, top right, bottom right and bottom left corners of `rect`
+/// with the rotation and scale of the node's `GlobalTransform` applied around the node's center
+pub(crate) fn ui_quad_corners(rect: Rect, node_global_transform: &GlobalTransform) -> [Vec2; 4] {
+    let node_center = node_global_transform.translation().truncate();
+    [
+        Vec2::new(rect.min.x, rect.min.y),
+        Vec2::new(rect.max.x, rect.min.y),
+        Vec2::new(rect.max.x, rect.max.y),
+        Vec2::new(rect.min.x, rect.max.y),
+    ]
+    .map(|corner| {
+        node_global_transform
+            .transform_point((corner - node_center).extend(0.0))
+            .truncate()
+    })
+}
+
+/// inverse of [`ui_quad_corners`], returns the position in the unrotated rect of the node
+pub(crate) fn ui_point_in_node(pos: Vec2, node_global_transform: &GlobalTransform) -> Vec2 {
+    let node_center = node_global_transform.translation().truncate();
+    node_global_transform
+        .affine()
+        .inverse()
+        .transform_point3(pos.extend(0.0))
+        .truncate()
+        + node_center
+}
+
+pub(crate) fn scale_rect(rect: Rect, scale: f32) -> Rect {
+    Rect {
+        min: rect.min * scale,
+        max: rect.max * scale,
+    }
+}
+
+/// rounds the rect to the pixel grid where `pixels_per_unit` is the number of physical pixels
+/// in one unit of the rect
+pub(crate) fn snap_to_pixel_grid(rect: Rect, pixels_per_unit: f32) -> Rect {
+    if pixels_per_unit <= 0.0 {
+        return rect;
+    }
+    Rect {
+        min: (rect.min * pixels_per_unit).round() / pixels_per_unit,
+        max: (rect.max * pixels_per_unit).round() / pixels_per_unit,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::math::{Quat, UVec2};
+    use bevy::prelude::{OrthographicProjection, PerspectiveProjection};
+    use bevy::render::camera::Viewport;
+
+    use super::*;
+
+    fn projection_for(viewport_size: Vec2) -> OrthographicProjection {
+        let mut projection = OrthographicProjection::default();
+        projection.update(viewport_size.x, viewport_size.y);
+        projection
+    }
+
+    #[test]
+    fn test_ui_to_world_full_viewport() {
+        let viewport_size = Vec2::new(800.0, 600.0);
+        let projection = projection_for(viewport_size);
+        let camera_transform = Transform::from_xyz(100.0, 50.0, 0.0);
+        let to_world = |pos| ui_to_world(pos, viewport_size, &camera_transform, &projection);
+        assert_eq!(to_world(Vec2::new(400.0, 300.0)), Vec2::new(100.0, 50.0));
+        assert_eq!(to_world(Vec2::ZERO), Vec2::new(-300.0, 350.0));
+        assert_eq!(to_world(Vec2::new(800.0, 600.0)), Vec2::new(500.0, -250.0));
+    }
+
+    #[test]
+    fn test_ui_to_world_partial_viewport() {
+        // docked panel in the bottom right quarter of a 800x600 window,
+        // positions are relative to the panel
+        let viewport_size = Vec2::new(400.0, 300.0);
+        let projection = projection_for(viewport_size);
+        let camera_transform = Transform::from_xyz(10.0, 10.0, 0.0);
+        let to_world = |pos| ui_to_world(pos, viewport_size, &camera_transform, &projection);
+        assert_eq!(to_world(Vec2::new(200.0, 150.0)), Vec2::new(10.0, 10.0));
+        assert_eq!(to_world(Vec2::ZERO), Vec2::new(-190.0, 160.0));
+        // positions outside of the viewport extrapolate
+        assert_eq!(to_world(Vec2::new(-200.0, 150.0)), Vec2::new(-390.0, 10.0));
+    }
+
+    #[test]
+    fn test_camera_logical_viewport_rect() {
+        let mut window = Window::default();
+        window.resolution.set(800.0, 600.0);
+        window.resolution.set_scale_factor(2.0);
+        let mut camera = Camera::default();
+        assert_eq!(
+            camera_logical_viewport_rect(&camera, &window),
+            Rect::new(0.0, 0.0, 800.0, 600.0)
+        );
+        camera.viewport = Some(Viewport {
+            physical_position: UVec2::new(800, 0),
+            physical_size: UVec2::new(800, 1200),
+            ..Default::default()
+        });
+        assert_eq!(
+            camera_logical_viewport_rect(&camera, &window),
+            Rect::new(400.0, 0.0, 800.0, 600.0)
+        );
+    }
+
+    #[test]
+    fn test_snap_to_pixel_grid() {
+        let rect = Rect::new(10.2, 10.8, 20.4, 20.6);
+        assert_eq!(
+            snap_to_pixel_grid(rect, 1.0),
+            Rect::new(10.0, 11.0, 20.0, 21.0)
+        );
+        assert_eq!(
+            snap_to_pixel_grid(rect, 2.0),
+            Rect::new(10.0, 11.0, 20.5, 20.5)
+        );
+        assert_eq!(snap_to_pixel_grid(rect, 0.0), rect);
+    }
+
+    #[test]
+    fn test_ui_point_in_node() {
+        let node_global_transform = GlobalTransform::from(
+            Transform::from_xyz(50.0, 50.0, 0.0)
+                .with_rotation(Quat::from_rotation_z(0.5))
+                .with_scale(Vec3::splat(2.0)),
+        );
+        let rect = Rect::from_center_size(Vec2::splat(50.0), Vec2::new(20.0, 10.0));
+        let corners = ui_quad_corners(rect, &node_global_transform);
+        let top_left = ui_point_in_node(corners[0], &node_global_transform);
+        let bottom_right = ui_point_in_node(corners[2], &node_global_transform);
+        assert!(top_left.abs_diff_eq(rect.min, 1e-4));
+        assert!(bottom_right.abs_diff_eq(rect.max, 1e-4));
+    }
+
+    #[test]
+    fn test_world_to_ui_round_trip() {
+        let viewport_size = Vec2::new(400.0, 300.0);
+        let camera_transform = Transform::from_xyz(10.0, 20.0, 500.0);
+        let orthographic = projection_for(viewport_size);
+        let perspective = PerspectiveProjection {
+            aspect_ratio: viewport_size.x / viewport_size.y,
+            ..PerspectiveProjection::default()
+        };
+        for pos in [Vec2::new(200.0, 150.0), Vec2::new(10.0, 20.0)] {
+            let world = ui_to_world(pos, viewport_size, &camera_transform, &orthographic);
+            let ui = world_to_ui(
+                world.extend(0.0),
+                viewport_size,
+                &camera_transform,
+                &orthographic,
+            );
+            assert!(ui.unwrap().abs_diff_eq(pos, 1e-3));
 
-    // invert y to match world coordinates
-    Vec2::new(world_pos.x, world_pos.y)
+            let world = ui_to_world(pos, viewport_size, &camera_transform, &perspective);
+            let ui = world_to_ui(
+                world.extend(0.0),
+                viewport_size,
+                &camera_transform,
+                &perspective,
+            );
+            assert!(ui.unwrap().abs_diff_eq(pos, 1e-3));
+        }
+        // behind the camera
+        assert_eq!(
+            world_to_ui(
+                Vec3::new(0.0, 0.0, 1000.0),
+                viewport_size,
+                &camera_transform,
+                &perspective
+            ),
+            None
+        );
+    }
 }